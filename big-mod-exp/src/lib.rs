@@ -0,0 +1,171 @@
+//! Big integer modular exponentiation via the `sol_big_mod_exp` syscall,
+//! and RSA signature verification built on top of it.
+//!
+//! All integers are big-endian byte slices of arbitrary length up to [`MAX_LEN`].
+//!
+//! Off-chain, this falls back to [`solana_big_mod_exp`], the same impl used by the runtime,
+//! if the `solana-big-mod-exp` feature is enabled.
+
+#![cfg_attr(not(test), no_std)]
+#![allow(unexpected_cfgs)]
+
+use core::fmt::Display;
+
+mod rsa;
+
+pub use rsa::*;
+
+/// Maximum length in bytes of each of base, exponent and modulus that the runtime accepts.
+///
+/// Exceeding this aborts the program, so [`big_mod_exp`] checks this before making the syscall.
+pub const MAX_LEN: usize = 512;
+
+/// Same layout as the syscall's params struct
+#[cfg(target_os = "solana")]
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+struct BigModExpParams {
+    base: *const u8,
+    base_len: u64,
+    exponent: *const u8,
+    exponent_len: u64,
+    modulus: *const u8,
+    modulus_len: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BigModExpError {
+    /// base, exponent or modulus is longer than [`MAX_LEN`]
+    InputTooLong,
+
+    /// `out.len() != modulus.len()`
+    OutLenMismatch,
+}
+
+impl Display for BigModExpError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::InputTooLong => "InputTooLong",
+            Self::OutLenMismatch => "OutLenMismatch",
+        })
+    }
+}
+
+impl core::error::Error for BigModExpError {}
+
+/// Writes `base ^ exponent mod modulus` to `out`, left-padded with zeros to `modulus.len()`.
+///
+/// `out.len()` must equal `modulus.len()`.
+///
+/// Following the runtime, the result is 0 if `modulus` is 0 or 1.
+#[inline]
+pub fn big_mod_exp(
+    base: &[u8],
+    exponent: &[u8],
+    modulus: &[u8],
+    out: &mut [u8],
+) -> Result<(), BigModExpError> {
+    if [base, exponent, modulus].iter().any(|s| s.len() > MAX_LEN) {
+        return Err(BigModExpError::InputTooLong);
+    }
+    if out.len() != modulus.len() {
+        return Err(BigModExpError::OutLenMismatch);
+    }
+
+    #[cfg(target_os = "solana")]
+    {
+        let params = BigModExpParams {
+            base: base.as_ptr(),
+            base_len: base.len() as u64,
+            exponent: exponent.as_ptr(),
+            exponent_len: exponent.len() as u64,
+            modulus: modulus.as_ptr(),
+            modulus_len: modulus.len() as u64,
+        };
+        // syscall always returns 0, failures abort the program
+        unsafe {
+            jiminy_syscall::sol_big_mod_exp(core::ptr::from_ref(&params).cast(), out.as_mut_ptr());
+        }
+        Ok(())
+    }
+
+    #[cfg(all(not(target_os = "solana"), any(test, feature = "solana-big-mod-exp")))]
+    {
+        out.copy_from_slice(&solana_big_mod_exp::big_mod_exp(base, exponent, modulus));
+        Ok(())
+    }
+
+    #[cfg(all(
+        not(target_os = "solana"),
+        not(any(test, feature = "solana-big-mod-exp"))
+    ))]
+    {
+        core::hint::black_box((base, exponent, modulus, out));
+        unreachable!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn small_matches_u128(base: u64, exponent in 0u32..64, modulus: u64) {
+            let mut out = [0u8; 8];
+            big_mod_exp(&base.to_be_bytes(), &exponent.to_be_bytes(), &modulus.to_be_bytes(), &mut out).unwrap();
+
+            let expected = if modulus <= 1 {
+                0
+            } else {
+                let m = modulus as u128;
+                (0..exponent).fold(1u128, |acc, _| acc * base as u128 % m) % m
+            };
+            prop_assert_eq!(u64::from_be_bytes(out), expected as u64);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn leading_zeros_dont_change_value(
+            base in vec(any::<u8>(), 0..=64),
+            exponent in vec(any::<u8>(), 0..=8),
+            modulus in vec(any::<u8>(), 0..=64),
+            zeros in 0usize..=8,
+        ) {
+            let mut out = vec![0u8; modulus.len()];
+            big_mod_exp(&base, &exponent, &modulus, &mut out).unwrap();
+
+            let pad = |s: &[u8]| [vec![0u8; zeros], s.to_vec()].concat();
+            let mut padded_out = vec![0u8; modulus.len() + zeros];
+            big_mod_exp(&pad(&base), &pad(&exponent), &pad(&modulus), &mut padded_out).unwrap();
+            prop_assert_eq!(padded_out, pad(&out));
+        }
+    }
+
+    #[test]
+    fn invalid_lens_err() {
+        let long = [1u8; MAX_LEN + 1];
+        let max = [1u8; MAX_LEN];
+        let mut out = [0u8; MAX_LEN];
+        for (b, e, m) in [
+            (&long[..], &[1][..], &max[..]),
+            (&[1], &long, &max),
+            (&[1], &[1], &long),
+        ] {
+            let mut out = vec![0u8; m.len()];
+            assert_eq!(
+                big_mod_exp(b, e, m, &mut out),
+                Err(BigModExpError::InputTooLong)
+            );
+        }
+        assert_eq!(
+            big_mod_exp(&[2], &[3], &max, &mut out[1..]),
+            Err(BigModExpError::OutLenMismatch)
+        );
+        assert_eq!(big_mod_exp(&max, &[3], &max, &mut out), Ok(()));
+    }
+}
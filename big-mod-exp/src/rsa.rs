@@ -0,0 +1,220 @@
+use core::fmt::Display;
+
+use jiminy_hash::HASH_LEN;
+
+use crate::{big_mod_exp, MAX_LEN};
+
+/// DER-encoded DigestInfo prefix of a SHA-256 hash, RFC 8017 section 9.2 note 1
+pub const SHA256_DIGEST_INFO_PREFIX: [u8; 19] = [
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05,
+    0x00, 0x04, 0x20,
+];
+
+const SHA256_DIGEST_INFO_LEN: usize = SHA256_DIGEST_INFO_PREFIX.len() + HASH_LEN;
+
+/// Smallest modulus that can fit a PKCS#1 v1.5 encoded SHA-256 DigestInfo:
+/// `0x00 || 0x01 || PS || 0x00 || DigestInfo` where PS is at least 8 `0xff`s
+pub const RSA_SHA256_MIN_MODULUS_LEN: usize = SHA256_DIGEST_INFO_LEN + 11;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RsaVerifyError {
+    /// Modulus is shorter than [`RSA_SHA256_MIN_MODULUS_LEN`] or longer than [`MAX_LEN`]
+    InvalidModulusLen,
+
+    /// Exponent is longer than [`MAX_LEN`]
+    InvalidExponentLen,
+
+    /// Signature length != modulus length
+    InvalidSigLen,
+
+    /// Signature is not less than the modulus, or does not verify
+    InvalidSig,
+}
+
+impl Display for RsaVerifyError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::InvalidModulusLen => "InvalidModulusLen",
+            Self::InvalidExponentLen => "InvalidExponentLen",
+            Self::InvalidSigLen => "InvalidSigLen",
+            Self::InvalidSig => "InvalidSig",
+        })
+    }
+}
+
+impl core::error::Error for RsaVerifyError {}
+
+/// RSASSA-PKCS1-v1_5 signature verification with SHA-256 (RFC 8017 section 8.2.2)
+/// of the concatenation of `msg`.
+///
+/// `modulus` and `exponent` are the big-endian public key `(n, e)`.
+#[inline]
+pub fn rsa_pkcs1v15_sha256_verify(
+    modulus: &[u8],
+    exponent: &[u8],
+    msg: &[&[u8]],
+    sig: &[u8],
+) -> Result<(), RsaVerifyError> {
+    rsa_pkcs1v15_sha256_verify_prehashed(modulus, exponent, &jiminy_hash::sha256(msg), sig)
+}
+
+/// [`rsa_pkcs1v15_sha256_verify`] with the SHA-256 hash of the message already computed
+#[inline]
+pub fn rsa_pkcs1v15_sha256_verify_prehashed(
+    modulus: &[u8],
+    exponent: &[u8],
+    hash: &[u8; HASH_LEN],
+    sig: &[u8],
+) -> Result<(), RsaVerifyError> {
+    let k = modulus.len();
+    if !(RSA_SHA256_MIN_MODULUS_LEN..=MAX_LEN).contains(&k) {
+        return Err(RsaVerifyError::InvalidModulusLen);
+    }
+    if exponent.len() > MAX_LEN {
+        return Err(RsaVerifyError::InvalidExponentLen);
+    }
+    if sig.len() != k {
+        return Err(RsaVerifyError::InvalidSigLen);
+    }
+    // same len big-endian so lexicographic order == numeric order.
+    // Must check this else sig + n would also verify
+    if sig >= modulus {
+        return Err(RsaVerifyError::InvalidSig);
+    }
+
+    let mut em = [0u8; MAX_LEN];
+    let em = &mut em[..k];
+    big_mod_exp(sig, exponent, modulus, em).map_err(|_| RsaVerifyError::InvalidSig)?;
+
+    // EM = 0x00 || 0x01 || PS || 0x00 || DigestInfo
+    let (header, rest) = em.split_at(2);
+    let (ps, rest) = rest.split_at(k - 3 - SHA256_DIGEST_INFO_LEN);
+    let (sep, digest_info) = rest.split_at(1);
+    let (prefix, em_hash) = digest_info.split_at(SHA256_DIGEST_INFO_PREFIX.len());
+    let is_valid = header == [0x00, 0x01]
+        && ps.iter().all(|b| *b == 0xff)
+        && sep == [0x00]
+        && prefix == SHA256_DIGEST_INFO_PREFIX
+        && em_hash == hash;
+    match is_valid {
+        true => Ok(()),
+        false => Err(RsaVerifyError::InvalidSig),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // 2048-bit key generated with pyca/cryptography, signing `MSG` with PKCS1v15 + SHA256
+
+    const MSG: &[u8] = b"jiminy rsa test";
+
+    const E: [u8; 3] = [0x01, 0x00, 0x01];
+
+    const N: [u8; 256] = [
+        0xe4, 0x07, 0xb7, 0x1d, 0x96, 0xc5, 0x14, 0x0b, 0xc9, 0xf9, 0x8d, 0x5f, 0x3e, 0x1b, 0xa9,
+        0xf2, 0xe6, 0xa9, 0xc6, 0xc5, 0x3e, 0x83, 0x0a, 0xa5, 0x4f, 0x43, 0x2c, 0x49, 0xf4, 0x71,
+        0x81, 0xd1, 0xf7, 0x09, 0xb7, 0xe8, 0x88, 0x5c, 0x32, 0x93, 0xab, 0x28, 0xda, 0xa7, 0xe3,
+        0x31, 0xfd, 0x7e, 0x27, 0x45, 0xbc, 0xfd, 0xca, 0xc0, 0xb4, 0xf7, 0x0c, 0x8e, 0xb6, 0x75,
+        0x06, 0x23, 0x48, 0x3e, 0x3b, 0x18, 0x4c, 0x5a, 0xaa, 0xa2, 0x9d, 0xea, 0x76, 0x31, 0xaa,
+        0xe9, 0x69, 0x10, 0xa2, 0xa0, 0xd6, 0x53, 0xcc, 0xcd, 0xd4, 0x35, 0xe9, 0x74, 0xfd, 0x40,
+        0xa9, 0x6e, 0x5f, 0xa4, 0x9e, 0xad, 0xe2, 0x1d, 0xdc, 0xaf, 0x6a, 0xf4, 0x62, 0xfc, 0xa1,
+        0xa3, 0xe4, 0x16, 0x4a, 0x4b, 0xda, 0xee, 0x4f, 0x1e, 0x6c, 0x04, 0x55, 0xab, 0x3f, 0xd0,
+        0x2e, 0x04, 0xa3, 0x0f, 0x6f, 0xa8, 0xcf, 0x94, 0x9a, 0x4c, 0x4b, 0x31, 0x7e, 0xa5, 0x32,
+        0x29, 0x65, 0x19, 0xa9, 0x48, 0xf3, 0x92, 0x3d, 0x65, 0x46, 0x67, 0x42, 0xbe, 0x0a, 0xed,
+        0x5c, 0xb8, 0xa4, 0x8d, 0x4c, 0xc0, 0x27, 0x99, 0xb9, 0xf7, 0x8f, 0x87, 0x32, 0x3f, 0xaf,
+        0x81, 0x4e, 0x06, 0x64, 0x4b, 0xaf, 0x4a, 0x19, 0x8b, 0x2f, 0xe1, 0x60, 0xd2, 0xd7, 0xb2,
+        0x60, 0x95, 0x48, 0x39, 0xfe, 0x2e, 0xb4, 0xb0, 0xb5, 0x1a, 0xb4, 0x46, 0x80, 0xa0, 0x81,
+        0xa8, 0xbd, 0xee, 0x38, 0x5f, 0x3d, 0x02, 0xf9, 0x86, 0x5d, 0x71, 0xad, 0xfa, 0xca, 0xa0,
+        0xcf, 0x12, 0x18, 0xa0, 0xc6, 0xa5, 0xcd, 0x33, 0x24, 0xc4, 0x31, 0x5d, 0x03, 0xb5, 0x86,
+        0x5d, 0x78, 0x1c, 0x3e, 0xa3, 0x66, 0xbb, 0x48, 0x06, 0x87, 0x8e, 0x3d, 0xf8, 0x0c, 0x67,
+        0x6c, 0xee, 0xac, 0xb4, 0x23, 0x74, 0x14, 0x63, 0x13, 0xe0, 0x74, 0xe1, 0x4b, 0xdc, 0x69,
+        0x6b,
+    ];
+
+    const SIG: [u8; 256] = [
+        0x5a, 0x93, 0x0b, 0x3b, 0xe9, 0x50, 0x3b, 0xeb, 0xb7, 0xca, 0x6e, 0xca, 0xc5, 0x58, 0xb7,
+        0x23, 0x33, 0xc8, 0xdb, 0xdc, 0x67, 0x80, 0x02, 0xa2, 0xc1, 0x0f, 0x75, 0x00, 0x0b, 0x53,
+        0x01, 0x8b, 0xe0, 0x10, 0x5a, 0x9e, 0x08, 0xeb, 0x2e, 0xc0, 0x51, 0x8c, 0xed, 0x9e, 0xfa,
+        0x8d, 0xe0, 0x3e, 0xd3, 0xff, 0xaf, 0xe0, 0x55, 0x19, 0x16, 0xea, 0x23, 0x93, 0x54, 0xef,
+        0xc7, 0x95, 0xc9, 0x9c, 0x2c, 0x40, 0x2b, 0x6e, 0x9a, 0xfd, 0xaf, 0x3b, 0xaf, 0x3d, 0x1e,
+        0x0a, 0x5e, 0x5f, 0x4b, 0xd3, 0x21, 0x84, 0x55, 0xfd, 0x47, 0x31, 0xf3, 0xbe, 0x32, 0x8d,
+        0x5d, 0xc3, 0xf5, 0x8b, 0x6c, 0x47, 0xf2, 0xf5, 0x7f, 0xc0, 0xbc, 0x3c, 0xa4, 0x66, 0xa9,
+        0x2e, 0x5a, 0x33, 0x18, 0xce, 0xf2, 0x7d, 0xae, 0xfc, 0x5f, 0xa3, 0x2b, 0x99, 0x72, 0x77,
+        0x1f, 0x37, 0x0a, 0xd2, 0x68, 0x6b, 0x4b, 0x08, 0xee, 0xa5, 0x7a, 0xc7, 0xfe, 0x42, 0xca,
+        0xc5, 0xa3, 0x42, 0x86, 0xc3, 0x77, 0x1b, 0x78, 0x3c, 0xe2, 0xae, 0x71, 0x7e, 0x16, 0xea,
+        0x36, 0xe1, 0xa2, 0x5e, 0x3f, 0x74, 0x73, 0x88, 0x37, 0xcf, 0xfb, 0xfe, 0xb2, 0xd2, 0x10,
+        0x99, 0x85, 0xe5, 0xb4, 0xb4, 0x0c, 0xf6, 0x21, 0xf9, 0xa9, 0xa5, 0x55, 0x33, 0x7e, 0x25,
+        0x9a, 0x1c, 0x31, 0x20, 0x78, 0xd0, 0xcd, 0x1f, 0x9c, 0xc7, 0xf8, 0x05, 0xbe, 0x44, 0x18,
+        0x9f, 0x0e, 0x09, 0x61, 0x91, 0x3e, 0x4b, 0x88, 0xf4, 0xd1, 0x18, 0x99, 0x90, 0xa0, 0x2a,
+        0x54, 0xc4, 0xc4, 0x17, 0x91, 0xc7, 0x95, 0xc6, 0x2b, 0x6f, 0x33, 0xe6, 0x66, 0x42, 0xce,
+        0x99, 0x9e, 0x94, 0xfb, 0xe2, 0x62, 0x33, 0x2e, 0x48, 0xf7, 0x22, 0x29, 0xa1, 0x00, 0xac,
+        0xda, 0x4a, 0x09, 0xaf, 0x39, 0x25, 0x5a, 0x08, 0x2f, 0x65, 0x5f, 0xe0, 0x77, 0x4c, 0x60,
+        0xaa,
+    ];
+
+    #[test]
+    fn known_vector_verifies() {
+        assert_eq!(rsa_pkcs1v15_sha256_verify(&N, &E, &[MSG], &SIG), Ok(()));
+        assert_eq!(
+            rsa_pkcs1v15_sha256_verify(&N, &E, &[b"jiminy ", b"rsa test"], &SIG),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn sig_not_less_than_modulus_rejected() {
+        assert_eq!(
+            rsa_pkcs1v15_sha256_verify(&N, &E, &[MSG], &N),
+            Err(RsaVerifyError::InvalidSig)
+        );
+    }
+
+    #[test]
+    fn invalid_lens_rejected() {
+        assert_eq!(
+            rsa_pkcs1v15_sha256_verify(&N, &E, &[MSG], &SIG[1..]),
+            Err(RsaVerifyError::InvalidSigLen)
+        );
+        assert_eq!(
+            rsa_pkcs1v15_sha256_verify(
+                &N[..RSA_SHA256_MIN_MODULUS_LEN - 1],
+                &E,
+                &[MSG],
+                &SIG[..RSA_SHA256_MIN_MODULUS_LEN - 1]
+            ),
+            Err(RsaVerifyError::InvalidModulusLen)
+        );
+        assert_eq!(
+            rsa_pkcs1v15_sha256_verify(&N, &[1; MAX_LEN + 1], &[MSG], &SIG),
+            Err(RsaVerifyError::InvalidExponentLen)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn wrong_msg_rejected(msg: Vec<u8>) {
+            prop_assume!(msg != MSG);
+            prop_assert_eq!(
+                rsa_pkcs1v15_sha256_verify(&N, &E, &[&msg], &SIG),
+                Err(RsaVerifyError::InvalidSig)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn tampered_sig_rejected(i in 0..SIG.len(), flip in 1u8..) {
+            let mut sig = SIG;
+            sig[i] ^= flip;
+            prop_assert_eq!(
+                rsa_pkcs1v15_sha256_verify(&N, &E, &[MSG], &sig),
+                Err(RsaVerifyError::InvalidSig)
+            );
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use core::fmt::Display;
+
+use crate::{pairing_check, PairingElement, Scalar, G1, G2};
+
+/// A Groth16 verifying key.
+///
+/// `ic` is a slice so that verifying keys of any number of public inputs can be
+/// `const`s in the program without a const generic.
+/// `ic.len()` must be the number of public inputs + 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Groth16Vk<'a> {
+    pub alpha_g1: G1,
+    pub beta_g2: G2,
+    pub gamma_g2: G2,
+    pub delta_g2: G2,
+    pub ic: &'a [G1],
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Groth16Proof {
+    pub a: G1,
+    pub b: G2,
+    pub c: G1,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Groth16Error {
+    /// Number of public inputs != `vk.ic.len() - 1`
+    InvalidPublicInputsLen,
+
+    /// A public input is not less than the scalar field modulus
+    PublicInputNotCanonical,
+
+    /// A syscall errored while computing the linear combination of `vk.ic`
+    /// and the public inputs, e.g. because a point in `vk.ic` is invalid
+    PrepareInputsFailed,
+
+    /// Proof A is not a valid point
+    InvalidProofA,
+
+    /// The pairing syscall errored, e.g. because a point is invalid
+    PairingFailed,
+
+    /// The proof is well-formed but does not verify
+    VerificationFailed,
+}
+
+impl Display for Groth16Error {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::InvalidPublicInputsLen => "InvalidPublicInputsLen",
+            Self::PublicInputNotCanonical => "PublicInputNotCanonical",
+            Self::PrepareInputsFailed => "PrepareInputsFailed",
+            Self::InvalidProofA => "InvalidProofA",
+            Self::PairingFailed => "PairingFailed",
+            Self::VerificationFailed => "VerificationFailed",
+        })
+    }
+}
+
+impl core::error::Error for Groth16Error {}
+
+impl Groth16Vk<'_> {
+    /// Returns `vk.ic[0] + public_inputs[0] * vk.ic[1] + public_inputs[1] * vk.ic[2] + ...`
+    #[inline]
+    pub fn prepare_inputs(&self, public_inputs: &[Scalar]) -> Result<G1, Groth16Error> {
+        let (ic0, ic) = self
+            .ic
+            .split_first()
+            .ok_or(Groth16Error::InvalidPublicInputsLen)?;
+        if ic.len() != public_inputs.len() {
+            return Err(Groth16Error::InvalidPublicInputsLen);
+        }
+        public_inputs
+            .iter()
+            .zip(ic)
+            .try_fold(*ic0, |acc, (input, ic)| {
+                if !input.is_canonical() {
+                    return Err(Groth16Error::PublicInputNotCanonical);
+                }
+                ic.mul(input)
+                    .and_then(|p| acc.add(&p))
+                    .ok_or(Groth16Error::PrepareInputsFailed)
+            })
+    }
+
+    /// Verifies `proof` against `public_inputs`, checking that
+    /// `e(-A, B) * e(alpha, beta) * e(L, gamma) * e(C, delta) = 1`
+    /// where `L` is [`Self::prepare_inputs`]
+    #[inline]
+    pub fn verify(
+        &self,
+        proof: &Groth16Proof,
+        public_inputs: &[Scalar],
+    ) -> Result<(), Groth16Error> {
+        let l = self.prepare_inputs(public_inputs)?;
+        let neg_a = proof.a.neg().ok_or(Groth16Error::InvalidProofA)?;
+        let elems = [
+            PairingElement {
+                g1: neg_a,
+                g2: proof.b,
+            },
+            PairingElement {
+                g1: self.alpha_g1,
+                g2: self.beta_g2,
+            },
+            PairingElement {
+                g1: l,
+                g2: self.gamma_g2,
+            },
+            PairingElement {
+                g1: proof.c,
+                g2: self.delta_g2,
+            },
+        ];
+        match pairing_check(&elems) {
+            None => Err(Groth16Error::PairingFailed),
+            Some(false) => Err(Groth16Error::VerificationFailed),
+            Some(true) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fr;
+    use ark_ff::{Field, Zero};
+    use proptest::{collection::vec, prelude::*};
+
+    use crate::{
+        tests::{any_fr, g1, g2, scalar},
+        SCALAR_FIELD_MODULUS,
+    };
+
+    use super::*;
+
+    /// Generates a valid (vk.ic, vk, proof, public inputs) by picking all
+    /// discrete logs and solving for C's
+    fn any_valid() -> impl Strategy<Value = (Vec<G1>, Groth16Vk<'static>, Groth16Proof, Vec<Fr>)> {
+        (
+            [any_fr(), any_fr(), any_fr(), any_fr(), any_fr(), any_fr()],
+            vec((any_fr(), any_fr()), 0..4),
+            any_fr(),
+        )
+            .prop_filter("delta = 0", |([.., delta], ..)| !delta.is_zero())
+            .prop_map(|([alpha, beta, gamma, delta, a, b], pairs, ic0)| {
+                let (ic, inputs): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+                let l = ic
+                    .iter()
+                    .zip(&inputs)
+                    .fold(ic0, |acc, (ic, x)| acc + ic * x);
+                // a * b = alpha * beta + l * gamma + c * delta
+                let c = (a * b - alpha * beta - l * gamma) * delta.inverse().unwrap();
+
+                let ic: Vec<_> = core::iter::once(ic0).chain(ic).map(|s| g1(&s)).collect();
+                let vk = Groth16Vk {
+                    alpha_g1: g1(&alpha),
+                    beta_g2: g2(&beta),
+                    gamma_g2: g2(&gamma),
+                    delta_g2: g2(&delta),
+                    ic: &[],
+                };
+                let proof = Groth16Proof {
+                    a: g1(&a),
+                    b: g2(&b),
+                    c: g1(&c),
+                };
+                (ic, vk, proof, inputs)
+            })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn valid_proof_verifies(
+            (ic, vk, proof, inputs) in any_valid(),
+            tamper in any_fr(),
+        ) {
+            let vk = Groth16Vk { ic: &ic, ..vk };
+            let inputs: Vec<_> = inputs.iter().map(scalar).collect();
+            prop_assert_eq!(vk.verify(&proof, &inputs), Ok(()));
+
+            // wrong proof
+            let tampered = Groth16Proof { c: g1(&tamper), ..proof };
+            if tampered != proof {
+                prop_assert_eq!(
+                    vk.verify(&tampered, &inputs),
+                    Err(Groth16Error::VerificationFailed)
+                );
+            }
+
+            // wrong public inputs
+            if let Some(first) = inputs.first() {
+                let mut wrong = inputs.clone();
+                wrong[0] = scalar(&tamper);
+                if wrong[0] != *first {
+                    prop_assert_eq!(
+                        vk.verify(&proof, &wrong),
+                        Err(Groth16Error::VerificationFailed)
+                    );
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn malformed_inputs_err((ic, vk, proof, inputs) in any_valid()) {
+            let vk = Groth16Vk { ic: &ic, ..vk };
+            let mut inputs: Vec<_> = inputs.iter().map(scalar).collect();
+
+            inputs.push(Scalar::default());
+            prop_assert_eq!(
+                vk.verify(&proof, &inputs),
+                Err(Groth16Error::InvalidPublicInputsLen)
+            );
+            inputs.pop();
+
+            if !inputs.is_empty() {
+                inputs[0] = Scalar(SCALAR_FIELD_MODULUS);
+                prop_assert_eq!(
+                    vk.verify(&proof, &inputs),
+                    Err(Groth16Error::PublicInputNotCanonical)
+                );
+            }
+
+            let empty_ic = Groth16Vk { ic: &[], ..vk };
+            prop_assert_eq!(
+                empty_ic.verify(&proof, &[]),
+                Err(Groth16Error::InvalidPublicInputsLen)
+            );
+        }
+    }
+}
@@ -0,0 +1,551 @@
+//! alt_bn128 (BN254) group operations and point compression via the
+//! `sol_alt_bn128_group_op` and `sol_alt_bn128_compression` syscalls.
+//!
+//! All points and scalars are big-endian, EIP-197 encoded:
+//! - G1 points are `be(x) || be(y)`
+//! - G2 points are `be(x1) || be(x0) || be(y1) || be(y0)`
+//! - the point at infinity is all zeros
+//!
+//! All operations return `None` if the syscall returns an error e.g. an
+//! input point is not on the curve.
+//!
+//! Off-chain, these fall back to [`solana_bn254`], the same impl used by the runtime,
+//! if the `solana-bn254` feature is enabled.
+
+#![cfg_attr(not(test), no_std)]
+#![allow(unexpected_cfgs)]
+
+use core::mem::{size_of, MaybeUninit};
+
+mod groth16;
+
+pub use groth16::*;
+
+#[cfg(all(not(target_os = "solana"), any(test, feature = "solana-bn254")))]
+mod native;
+
+pub const FIELD_LEN: usize = 32;
+
+pub const SCALAR_LEN: usize = 32;
+
+pub const G1_LEN: usize = FIELD_LEN * 2;
+
+pub const G2_LEN: usize = FIELD_LEN * 4;
+
+pub const G1_COMPRESSED_LEN: usize = G1_LEN / 2;
+
+pub const G2_COMPRESSED_LEN: usize = G2_LEN / 2;
+
+pub const PAIRING_ELEMENT_LEN: usize = G1_LEN + G2_LEN;
+
+// `sol_alt_bn128_group_op` ops.
+// 1 is reserved for G1 subtraction, which the runtime does not support yet.
+
+pub const ADD: u64 = 0;
+pub const MUL: u64 = 2;
+pub const PAIRING: u64 = 3;
+
+// `sol_alt_bn128_compression` ops
+
+pub const G1_COMPRESS: u64 = 0;
+pub const G1_DECOMPRESS: u64 = 1;
+pub const G2_COMPRESS: u64 = 2;
+pub const G2_DECOMPRESS: u64 = 3;
+
+/// The base field modulus `p`, big-endian
+pub const BASE_FIELD_MODULUS: [u8; FIELD_LEN] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+/// The scalar field modulus `r` i.e. the order of G1 and G2, big-endian
+pub const SCALAR_FIELD_MODULUS: [u8; SCALAR_LEN] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// A 32-byte big-endian scalar
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Scalar(pub [u8; SCALAR_LEN]);
+
+impl Scalar {
+    /// Returns true if `self < r`, the scalar field modulus
+    #[inline]
+    pub const fn is_canonical(&self) -> bool {
+        be_lt(&self.0, &SCALAR_FIELD_MODULUS)
+    }
+}
+
+/// A G1 point, `be(x) || be(y)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct G1(pub [u8; G1_LEN]);
+
+/// A G2 point, `be(x1) || be(x0) || be(y1) || be(y0)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct G2(pub [u8; G2_LEN]);
+
+/// A compressed G1 point
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct G1Compressed(pub [u8; G1_COMPRESSED_LEN]);
+
+/// A compressed G2 point
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct G2Compressed(pub [u8; G2_COMPRESSED_LEN]);
+
+impl Default for G1 {
+    #[inline]
+    fn default() -> Self {
+        Self::INFINITY
+    }
+}
+
+impl Default for G2 {
+    #[inline]
+    fn default() -> Self {
+        Self::INFINITY
+    }
+}
+
+impl G1 {
+    pub const INFINITY: Self = Self([0; G1_LEN]);
+
+    #[inline]
+    pub const fn x(&self) -> &[u8; FIELD_LEN] {
+        // safety: [..32] of [u8; 64] is [u8; 32]. align = 1
+        unsafe { &*self.0.as_ptr().cast() }
+    }
+
+    #[inline]
+    pub const fn y(&self) -> &[u8; FIELD_LEN] {
+        // safety: [32..] of [u8; 64] is [u8; 32]. align = 1
+        unsafe { &*self.0.as_ptr().add(FIELD_LEN).cast() }
+    }
+
+    /// Returns `-self` i.e. `(x, p - y)`.
+    ///
+    /// This does not check that `self` is on the curve,
+    /// but returns `None` if `y >= p` since that cannot be a valid point.
+    #[inline]
+    pub const fn neg(&self) -> Option<Self> {
+        if be_is_zero(&self.0) {
+            return Some(Self::INFINITY);
+        }
+        let y = self.y();
+        if !be_lt(y, &BASE_FIELD_MODULUS) {
+            return None;
+        }
+        let mut res = *self;
+        // p > y so this never underflows
+        let mut borrow = 0u8;
+        let mut i = FIELD_LEN;
+        while i > 0 {
+            i -= 1;
+            let (d, b1) = BASE_FIELD_MODULUS[i].overflowing_sub(y[i]);
+            let (d, b2) = d.overflowing_sub(borrow);
+            res.0[FIELD_LEN + i] = d;
+            borrow = (b1 || b2) as u8;
+        }
+        Some(res)
+    }
+
+    #[inline]
+    pub fn add(&self, rhs: &Self) -> Option<Self> {
+        let mut res = MaybeUninit::uninit();
+        self.add_to(rhs, &mut res)?;
+        Some(unsafe { res.assume_init() })
+    }
+
+    #[inline]
+    pub fn add_to<'dst>(
+        &self,
+        rhs: &Self,
+        res: &'dst mut MaybeUninit<Self>,
+    ) -> Option<&'dst mut Self> {
+        // G1s are contiguous in memory only if they're in an array
+        let input: [Self; 2] = [*self, *rhs];
+        syscall(group_op, ADD, as_bytes(&input), res)
+    }
+
+    /// `scalar * self`
+    #[inline]
+    pub fn mul(&self, scalar: &Scalar) -> Option<Self> {
+        let mut res = MaybeUninit::uninit();
+        self.mul_to(scalar, &mut res)?;
+        Some(unsafe { res.assume_init() })
+    }
+
+    #[inline]
+    pub fn mul_to<'dst>(
+        &self,
+        scalar: &Scalar,
+        res: &'dst mut MaybeUninit<Self>,
+    ) -> Option<&'dst mut Self> {
+        let mut input = [0u8; G1_LEN + SCALAR_LEN];
+        input[..G1_LEN].copy_from_slice(&self.0);
+        input[G1_LEN..].copy_from_slice(&scalar.0);
+        syscall(group_op, MUL, &input, res)
+    }
+
+    #[inline]
+    pub fn compress(&self) -> Option<G1Compressed> {
+        let mut res = MaybeUninit::uninit();
+        self.compress_to(&mut res)?;
+        Some(unsafe { res.assume_init() })
+    }
+
+    #[inline]
+    pub fn compress_to<'dst>(
+        &self,
+        res: &'dst mut MaybeUninit<G1Compressed>,
+    ) -> Option<&'dst mut G1Compressed> {
+        syscall(compression, G1_COMPRESS, &self.0, res)
+    }
+}
+
+impl G1Compressed {
+    #[inline]
+    pub fn decompress(&self) -> Option<G1> {
+        let mut res = MaybeUninit::uninit();
+        self.decompress_to(&mut res)?;
+        Some(unsafe { res.assume_init() })
+    }
+
+    #[inline]
+    pub fn decompress_to<'dst>(&self, res: &'dst mut MaybeUninit<G1>) -> Option<&'dst mut G1> {
+        syscall(compression, G1_DECOMPRESS, &self.0, res)
+    }
+}
+
+impl G2 {
+    pub const INFINITY: Self = Self([0; G2_LEN]);
+
+    #[inline]
+    pub fn compress(&self) -> Option<G2Compressed> {
+        let mut res = MaybeUninit::uninit();
+        self.compress_to(&mut res)?;
+        Some(unsafe { res.assume_init() })
+    }
+
+    #[inline]
+    pub fn compress_to<'dst>(
+        &self,
+        res: &'dst mut MaybeUninit<G2Compressed>,
+    ) -> Option<&'dst mut G2Compressed> {
+        syscall(compression, G2_COMPRESS, &self.0, res)
+    }
+}
+
+impl G2Compressed {
+    #[inline]
+    pub fn decompress(&self) -> Option<G2> {
+        let mut res = MaybeUninit::uninit();
+        self.decompress_to(&mut res)?;
+        Some(unsafe { res.assume_init() })
+    }
+
+    #[inline]
+    pub fn decompress_to<'dst>(&self, res: &'dst mut MaybeUninit<G2>) -> Option<&'dst mut G2> {
+        syscall(compression, G2_DECOMPRESS, &self.0, res)
+    }
+}
+
+/// A (G1, G2) pair that is an input to [`pairing_check`].
+///
+/// Same layout as the syscall expects, so that a slice of these can be passed as-is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct PairingElement {
+    pub g1: G1,
+    pub g2: G2,
+}
+
+/// Returns whether `e(elements[0].g1, elements[0].g2) * e(elements[1].g1, elements[1].g2) * ...`
+/// is the identity element of the target group.
+///
+/// An empty `elements` trivially returns `Some(true)`.
+#[inline]
+pub fn pairing_check(elements: &[PairingElement]) -> Option<bool> {
+    let mut res: MaybeUninit<[u8; 32]> = MaybeUninit::uninit();
+    let res = syscall(group_op, PAIRING, as_bytes(elements), &mut res)?;
+    // result is a big-endian 1 or 0
+    Some(res[31] == 1)
+}
+
+type SyscallFn = fn(u64, &[u8], &mut [MaybeUninit<u8>]) -> Option<()>;
+
+/// `T` must be a `[u8; N]` or a `repr(transparent)` wrapper around one
+#[inline(always)]
+fn syscall<'dst, T>(
+    f: SyscallFn,
+    op: u64,
+    input: &[u8],
+    res: &'dst mut MaybeUninit<T>,
+) -> Option<&'dst mut T> {
+    // safety: T is a byte array, MaybeUninit<u8> has the same layout as u8
+    let res_bytes = unsafe {
+        core::slice::from_raw_parts_mut(res.as_mut_ptr().cast::<MaybeUninit<u8>>(), size_of::<T>())
+    };
+    f(op, input, res_bytes)?;
+    Some(unsafe { res.assume_init_mut() })
+}
+
+#[inline(always)]
+fn group_op(op: u64, input: &[u8], res: &mut [MaybeUninit<u8>]) -> Option<()> {
+    #[cfg(target_os = "solana")]
+    {
+        let code = unsafe {
+            jiminy_syscall::sol_alt_bn128_group_op(
+                op,
+                input.as_ptr(),
+                input.len() as u64,
+                res.as_mut_ptr().cast(),
+            )
+        };
+        match code {
+            0 => Some(()),
+            _ => None,
+        }
+    }
+
+    #[cfg(all(not(target_os = "solana"), any(test, feature = "solana-bn254")))]
+    {
+        native::group_op(op, input, res)
+    }
+
+    #[cfg(all(not(target_os = "solana"), not(any(test, feature = "solana-bn254"))))]
+    {
+        core::hint::black_box((op, input, res));
+        unreachable!()
+    }
+}
+
+#[inline(always)]
+fn compression(op: u64, input: &[u8], res: &mut [MaybeUninit<u8>]) -> Option<()> {
+    #[cfg(target_os = "solana")]
+    {
+        let code = unsafe {
+            jiminy_syscall::sol_alt_bn128_compression(
+                op,
+                input.as_ptr(),
+                input.len() as u64,
+                res.as_mut_ptr().cast(),
+            )
+        };
+        match code {
+            0 => Some(()),
+            _ => None,
+        }
+    }
+
+    #[cfg(all(not(target_os = "solana"), any(test, feature = "solana-bn254")))]
+    {
+        native::compression(op, input, res)
+    }
+
+    #[cfg(all(not(target_os = "solana"), not(any(test, feature = "solana-bn254"))))]
+    {
+        core::hint::black_box((op, input, res));
+        unreachable!()
+    }
+}
+
+/// `T` must be a `[u8; N]` or a `repr(transparent)`/`repr(C)` wrapper
+/// around them with no padding
+#[inline(always)]
+const fn as_bytes<T>(s: &[T]) -> &[u8] {
+    unsafe { core::slice::from_raw_parts(s.as_ptr().cast(), core::mem::size_of_val(s)) }
+}
+
+#[inline]
+const fn be_is_zero(a: &[u8]) -> bool {
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// `a < b` for big-endian unsigned ints
+#[inline]
+const fn be_lt<const N: usize>(a: &[u8; N], b: &[u8; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    false
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use ark_bn254::{Fr, G1Affine, G2Affine};
+    use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+    use ark_ff::{BigInteger, PrimeField, Zero};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    pub(crate) fn any_fr() -> impl Strategy<Value = Fr> {
+        any::<[u8; 64]>().prop_map(|b| Fr::from_le_bytes_mod_order(&b))
+    }
+
+    fn fq_be(f: &impl PrimeField) -> [u8; 32] {
+        f.into_bigint().to_bytes_be().try_into().unwrap()
+    }
+
+    pub(crate) fn scalar(f: &Fr) -> Scalar {
+        Scalar(fq_be(f))
+    }
+
+    pub(crate) fn g1(s: &Fr) -> G1 {
+        let p = (G1Affine::generator() * s).into_affine();
+        let mut res = [0u8; G1_LEN];
+        if let Some((x, y)) = p.xy() {
+            res[..32].copy_from_slice(&fq_be(x));
+            res[32..].copy_from_slice(&fq_be(y));
+        }
+        G1(res)
+    }
+
+    pub(crate) fn g2(s: &Fr) -> G2 {
+        let p = (G2Affine::generator() * s).into_affine();
+        let mut res = [0u8; G2_LEN];
+        if let Some((x, y)) = p.xy() {
+            for (chunk, f) in res.chunks_exact_mut(32).zip([x.c1, x.c0, y.c1, y.c0]) {
+                chunk.copy_from_slice(&fq_be(&f));
+            }
+        }
+        G2(res)
+    }
+
+    #[test]
+    fn moduli_eq_ark() {
+        assert_eq!(
+            BASE_FIELD_MODULUS.as_slice(),
+            ark_bn254::Fq::MODULUS.to_bytes_be()
+        );
+        assert_eq!(SCALAR_FIELD_MODULUS.as_slice(), Fr::MODULUS.to_bytes_be());
+    }
+
+    #[test]
+    fn pairing_element_layout() {
+        assert_eq!(size_of::<PairingElement>(), PAIRING_ELEMENT_LEN);
+        assert_eq!(core::mem::align_of::<PairingElement>(), 1);
+    }
+
+    proptest! {
+        #[test]
+        fn add_mul_neg(a in any_fr(), b in any_fr()) {
+            let [pa, pb] = [a, b].map(|s| g1(&s));
+            prop_assert_eq!(pa.add(&pb), Some(g1(&(a + b))));
+            prop_assert_eq!(pa.add(&G1::INFINITY), Some(pa));
+            prop_assert_eq!(pa.mul(&scalar(&b)), Some(g1(&(a * b))));
+            prop_assert_eq!(pa.neg(), Some(g1(&-a)));
+            prop_assert_eq!(pa.add(&pa.neg().unwrap()), Some(G1::INFINITY));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn compression_round_trip(a in any_fr()) {
+            let p1 = g1(&a);
+            prop_assert_eq!(p1.compress().unwrap().decompress(), Some(p1));
+            let p2 = g2(&a);
+            prop_assert_eq!(p2.compress().unwrap().decompress(), Some(p2));
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn pairing_check_bilinear(a in any_fr(), b in any_fr(), c in any_fr()) {
+            // e(aG1, bG2) * e(-abG1, G2) = 1
+            let elems = [
+                PairingElement { g1: g1(&a), g2: g2(&b) },
+                PairingElement { g1: g1(&-(a * b)), g2: g2(&Fr::from(1u8)) },
+            ];
+            prop_assert_eq!(pairing_check(&elems), Some(true));
+
+            // e(aG1, bG2) * e(-cG1, G2) = 1 iff c = ab
+            let elems = [
+                elems[0],
+                PairingElement { g1: g1(&-c), g2: g2(&Fr::from(1u8)) },
+            ];
+            prop_assert_eq!(pairing_check(&elems), Some(c == a * b));
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn pairing_check_matches_ark(a in any_fr(), b in any_fr()) {
+            let expected = ark_bn254::Bn254::pairing(
+                (G1Affine::generator() * a).into_affine(),
+                (G2Affine::generator() * b).into_affine(),
+            )
+            .is_zero();
+            prop_assert_eq!(
+                pairing_check(&[PairingElement { g1: g1(&a), g2: g2(&b) }]),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn empty_pairing_check_true() {
+        assert_eq!(pairing_check(&[]), Some(true));
+    }
+
+    proptest! {
+        #[test]
+        fn invalid_points_none(mut x: [u8; 32], mut y: [u8; 32], a in any_fr()) {
+            // keep coordinates < p. The runtime interprets the top bits of
+            // coordinates >= p as serialization flags instead of erroring
+            x[0] &= 0x1f;
+            y[0] &= 0x1f;
+            let mut bytes = [0u8; G1_LEN];
+            bytes[..32].copy_from_slice(&x);
+            bytes[32..].copy_from_slice(&y);
+            let p = G1(bytes);
+            // random (x, y) is overwhelmingly unlikely to be on the curve
+            prop_assume!(p != G1::INFINITY);
+            prop_assert_eq!(p.add(&g1(&a)), None);
+            prop_assert_eq!(p.mul(&scalar(&a)), None);
+            prop_assert_eq!(
+                pairing_check(&[PairingElement { g1: p, g2: g2(&a) }]),
+                None
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn neg_y_ge_p_none(y: [u8; 32]) {
+            let mut y = y;
+            y[0] |= 0x40;
+            let mut bytes = [1u8; G1_LEN];
+            bytes[32..].copy_from_slice(&y);
+            prop_assert_eq!(G1(bytes).neg(), None);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn scalar_is_canonical(s: [u8; 32]) {
+            let expected = Fr::from_be_bytes_mod_order(&s).into_bigint().to_bytes_be() == s;
+            prop_assert_eq!(Scalar(s).is_canonical(), expected);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Native fallbacks of the alt_bn128 syscalls for use off-chain,
+//! same as agave's syscall impls
+
+use core::mem::MaybeUninit;
+
+use solana_bn254::{
+    compression::prelude::{
+        alt_bn128_g1_compress, alt_bn128_g1_decompress, alt_bn128_g2_compress,
+        alt_bn128_g2_decompress,
+    },
+    prelude::{alt_bn128_g1_addition_be, alt_bn128_g1_multiplication_be, alt_bn128_pairing_be},
+};
+
+use crate::{ADD, G1_COMPRESS, G1_DECOMPRESS, G2_COMPRESS, G2_DECOMPRESS, MUL, PAIRING};
+
+#[inline]
+pub fn group_op(op: u64, input: &[u8], res: &mut [MaybeUninit<u8>]) -> Option<()> {
+    let out = match op {
+        ADD => alt_bn128_g1_addition_be(input),
+        MUL => alt_bn128_g1_multiplication_be(input),
+        PAIRING => alt_bn128_pairing_be(input),
+        _ => return None,
+    }
+    .ok()?;
+    write(res, &out)
+}
+
+#[inline]
+pub fn compression(op: u64, input: &[u8], res: &mut [MaybeUninit<u8>]) -> Option<()> {
+    match op {
+        G1_COMPRESS => write(res, &alt_bn128_g1_compress(input).ok()?),
+        G1_DECOMPRESS => write(res, &alt_bn128_g1_decompress(input).ok()?),
+        G2_COMPRESS => write(res, &alt_bn128_g2_compress(input).ok()?),
+        G2_DECOMPRESS => write(res, &alt_bn128_g2_decompress(input).ok()?),
+        _ => None,
+    }
+}
+
+#[inline]
+fn write(res: &mut [MaybeUninit<u8>], out: &[u8]) -> Option<()> {
+    if res.len() != out.len() {
+        return None;
+    }
+    res.iter_mut().zip(out).for_each(|(r, o)| {
+        r.write(*o);
+    });
+    Some(())
+}
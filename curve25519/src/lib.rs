@@ -0,0 +1,350 @@
+//! Curve25519 group operations via the `sol_curve_validate_point`, `sol_curve_group_op`
+//! and `sol_curve_multiscalar_mul` syscalls.
+//!
+//! Points are in their 32-byte compressed forms, scalars are 32-byte little-endian.
+//! All operations return `None` if any input point is not a valid point on the curve or
+//! any input scalar is not canonical i.e. not reduced modulo the group order.
+//!
+//! Off-chain, these fall back to [`curve25519_dalek`] if the `dalek` feature is enabled.
+
+#![cfg_attr(not(test), no_std)]
+#![allow(unexpected_cfgs)]
+
+use core::mem::MaybeUninit;
+
+#[cfg(all(not(target_os = "solana"), any(test, feature = "dalek")))]
+mod native;
+
+pub const CURVE25519_EDWARDS: u64 = 0;
+pub const CURVE25519_RISTRETTO: u64 = 1;
+
+pub const ADD: u64 = 0;
+pub const SUB: u64 = 1;
+pub const MUL: u64 = 2;
+
+pub const POINT_LEN: usize = 32;
+
+pub const SCALAR_LEN: usize = 32;
+
+/// Maximum number of (scalar, point) pairs the runtime accepts in a single
+/// multiscalar multiplication. Exceeding this aborts the program.
+pub const MAX_MULTISCALAR_MUL_LEN: usize = 512;
+
+/// A 32-byte little-endian scalar
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Scalar(pub [u8; SCALAR_LEN]);
+
+macro_rules! point_type {
+    (
+        $(#[$attr:meta])*
+        $Point:ident, $curve_id:expr, $native:ident
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        #[repr(transparent)]
+        pub struct $Point(pub [u8; POINT_LEN]);
+
+        impl $Point {
+            /// Returns true if this is a valid point on the curve
+            #[inline]
+            pub fn validate(&self) -> bool {
+                #[cfg(target_os = "solana")]
+                {
+                    // unused, syscall returns 0 for valid, 1 for invalid
+                    let mut unused = 0u8;
+                    let res = unsafe {
+                        jiminy_syscall::sol_curve_validate_point(
+                            $curve_id,
+                            self.0.as_ptr(),
+                            &mut unused,
+                        )
+                    };
+                    res == 0
+                }
+
+                #[cfg(all(not(target_os = "solana"), any(test, feature = "dalek")))]
+                {
+                    native::$native::validate(self)
+                }
+
+                #[cfg(all(not(target_os = "solana"), not(any(test, feature = "dalek"))))]
+                {
+                    core::hint::black_box(self);
+                    unreachable!()
+                }
+            }
+
+            #[inline]
+            pub fn add(&self, rhs: &Self) -> Option<Self> {
+                let mut res = MaybeUninit::uninit();
+                self.add_to(rhs, &mut res)?;
+                Some(unsafe { res.assume_init() })
+            }
+
+            #[inline]
+            pub fn add_to<'dst>(
+                &self,
+                rhs: &Self,
+                res: &'dst mut MaybeUninit<Self>,
+            ) -> Option<&'dst mut Self> {
+                Self::group_op(ADD, &self.0, rhs, res)
+            }
+
+            /// `self - rhs`
+            #[inline]
+            pub fn sub(&self, rhs: &Self) -> Option<Self> {
+                let mut res = MaybeUninit::uninit();
+                self.sub_to(rhs, &mut res)?;
+                Some(unsafe { res.assume_init() })
+            }
+
+            #[inline]
+            pub fn sub_to<'dst>(
+                &self,
+                rhs: &Self,
+                res: &'dst mut MaybeUninit<Self>,
+            ) -> Option<&'dst mut Self> {
+                Self::group_op(SUB, &self.0, rhs, res)
+            }
+
+            /// `scalar * self`
+            #[inline]
+            pub fn mul(&self, scalar: &Scalar) -> Option<Self> {
+                let mut res = MaybeUninit::uninit();
+                self.mul_to(scalar, &mut res)?;
+                Some(unsafe { res.assume_init() })
+            }
+
+            #[inline]
+            pub fn mul_to<'dst>(
+                &self,
+                scalar: &Scalar,
+                res: &'dst mut MaybeUninit<Self>,
+            ) -> Option<&'dst mut Self> {
+                Self::group_op(MUL, &scalar.0, self, res)
+            }
+
+            /// For [`MUL`], `left` is the scalar
+            #[inline]
+            fn group_op<'dst>(
+                op: u64,
+                left: &[u8; 32],
+                right: &Self,
+                res: &'dst mut MaybeUninit<Self>,
+            ) -> Option<&'dst mut Self> {
+                #[cfg(target_os = "solana")]
+                {
+                    let code = unsafe {
+                        jiminy_syscall::sol_curve_group_op(
+                            $curve_id,
+                            op,
+                            left.as_ptr(),
+                            right.0.as_ptr(),
+                            res.as_mut_ptr().cast(),
+                        )
+                    };
+                    match code {
+                        0 => Some(unsafe { res.assume_init_mut() }),
+                        _ => None,
+                    }
+                }
+
+                #[cfg(all(not(target_os = "solana"), any(test, feature = "dalek")))]
+                {
+                    Some(res.write(native::$native::group_op(op, left, right)?))
+                }
+
+                #[cfg(all(not(target_os = "solana"), not(any(test, feature = "dalek"))))]
+                {
+                    core::hint::black_box((op, left, right, res));
+                    unreachable!()
+                }
+            }
+
+            /// `scalars[0] * points[0] + scalars[1] * points[1] + ...`
+            ///
+            /// Also returns `None` if `scalars` and `points` have different lengths
+            /// or their lengths exceed [`MAX_MULTISCALAR_MUL_LEN`]
+            #[inline]
+            pub fn multiscalar_mul(scalars: &[Scalar], points: &[Self]) -> Option<Self> {
+                let mut res = MaybeUninit::uninit();
+                Self::multiscalar_mul_to(scalars, points, &mut res)?;
+                Some(unsafe { res.assume_init() })
+            }
+
+            #[inline]
+            pub fn multiscalar_mul_to<'dst>(
+                scalars: &[Scalar],
+                points: &[Self],
+                res: &'dst mut MaybeUninit<Self>,
+            ) -> Option<&'dst mut Self> {
+                if scalars.len() != points.len() || points.len() > MAX_MULTISCALAR_MUL_LEN {
+                    return None;
+                }
+
+                #[cfg(target_os = "solana")]
+                {
+                    let code = unsafe {
+                        jiminy_syscall::sol_curve_multiscalar_mul(
+                            $curve_id,
+                            scalars.as_ptr().cast(),
+                            points.as_ptr().cast(),
+                            points.len() as u64,
+                            res.as_mut_ptr().cast(),
+                        )
+                    };
+                    match code {
+                        0 => Some(unsafe { res.assume_init_mut() }),
+                        _ => None,
+                    }
+                }
+
+                #[cfg(all(not(target_os = "solana"), any(test, feature = "dalek")))]
+                {
+                    Some(res.write(native::$native::multiscalar_mul(scalars, points)?))
+                }
+
+                #[cfg(all(not(target_os = "solana"), not(any(test, feature = "dalek"))))]
+                {
+                    core::hint::black_box(res);
+                    unreachable!()
+                }
+            }
+        }
+    };
+}
+
+point_type!(
+    /// A compressed edwards25519 point
+    EdwardsPoint,
+    CURVE25519_EDWARDS,
+    edwards
+);
+
+point_type!(
+    /// A compressed ristretto255 point
+    RistrettoPoint,
+    CURVE25519_RISTRETTO,
+    ristretto
+);
+
+#[cfg(test)]
+mod tests {
+    use curve25519_dalek::{
+        constants::{ED25519_BASEPOINT_POINT, RISTRETTO_BASEPOINT_POINT},
+        scalar::Scalar as DalekScalar,
+    };
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    fn any_scalar() -> impl Strategy<Value = DalekScalar> {
+        any::<[u8; 64]>().prop_map(|b| DalekScalar::from_bytes_mod_order_wide(&b))
+    }
+
+    fn edwards(s: &DalekScalar) -> EdwardsPoint {
+        EdwardsPoint((s * ED25519_BASEPOINT_POINT).compress().to_bytes())
+    }
+
+    fn ristretto(s: &DalekScalar) -> RistrettoPoint {
+        RistrettoPoint((s * RISTRETTO_BASEPOINT_POINT).compress().to_bytes())
+    }
+
+    fn scalar(s: &DalekScalar) -> Scalar {
+        Scalar(s.to_bytes())
+    }
+
+    proptest! {
+        #[test]
+        fn edwards_group_ops(a in any_scalar(), b in any_scalar(), c in any_scalar()) {
+            let [pa, pb] = [a, b].map(|s| edwards(&s));
+            prop_assert!(pa.validate());
+            prop_assert_eq!(pa.add(&pb), Some(edwards(&(a + b))));
+            prop_assert_eq!(pa.sub(&pb), Some(edwards(&(a - b))));
+            prop_assert_eq!(pa.mul(&scalar(&c)), Some(edwards(&(a * c))));
+            prop_assert_eq!(
+                EdwardsPoint::multiscalar_mul(&[scalar(&c), scalar(&a)], &[pa, pb]),
+                Some(edwards(&(c * a + a * b)))
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ristretto_group_ops(a in any_scalar(), b in any_scalar(), c in any_scalar()) {
+            let [pa, pb] = [a, b].map(|s| ristretto(&s));
+            prop_assert!(pa.validate());
+            prop_assert_eq!(pa.add(&pb), Some(ristretto(&(a + b))));
+            prop_assert_eq!(pa.sub(&pb), Some(ristretto(&(a - b))));
+            prop_assert_eq!(pa.mul(&scalar(&c)), Some(ristretto(&(a * c))));
+            prop_assert_eq!(
+                RistrettoPoint::multiscalar_mul(&[scalar(&c), scalar(&a)], &[pa, pb]),
+                Some(ristretto(&(c * a + a * b)))
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn multiscalar_mul_eq_sum_of_muls(
+            pairs in vec((any_scalar(), any_scalar()), 0..8),
+        ) {
+            let scalars: Vec<_> = pairs.iter().map(|(s, _)| scalar(s)).collect();
+            let expected = pairs.iter().fold(DalekScalar::ZERO, |acc, (s, p)| acc + s * p);
+
+            let points: Vec<_> = pairs.iter().map(|(_, p)| edwards(p)).collect();
+            prop_assert_eq!(EdwardsPoint::multiscalar_mul(&scalars, &points), Some(edwards(&expected)));
+
+            let points: Vec<_> = pairs.iter().map(|(_, p)| ristretto(p)).collect();
+            prop_assert_eq!(RistrettoPoint::multiscalar_mul(&scalars, &points), Some(ristretto(&expected)));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn invalid_inputs_none(a in any_scalar(), bytes: [u8; 32]) {
+            let pa = edwards(&a);
+            let ra = ristretto(&a);
+
+            // non-canonical scalar
+            let mut non_canonical = [0xff; 32];
+            non_canonical[0] = bytes[0];
+            let non_canonical = Scalar(non_canonical);
+            prop_assert_eq!(pa.mul(&non_canonical), None);
+            prop_assert_eq!(ra.mul(&non_canonical), None);
+            prop_assert_eq!(EdwardsPoint::multiscalar_mul(&[non_canonical], &[pa]), None);
+
+            // mismatched lens
+            prop_assert_eq!(EdwardsPoint::multiscalar_mul(&[scalar(&a)], &[]), None);
+            prop_assert_eq!(RistrettoPoint::multiscalar_mul(&[], &[ra]), None);
+
+            // random bytes may or may not be valid points
+            let e = EdwardsPoint(bytes);
+            if !e.validate() {
+                prop_assert_eq!(e.add(&pa), None);
+                prop_assert_eq!(pa.sub(&e), None);
+                prop_assert_eq!(e.mul(&scalar(&a)), None);
+            }
+            let r = RistrettoPoint(bytes);
+            if !r.validate() {
+                prop_assert_eq!(r.add(&ra), None);
+                prop_assert_eq!(ra.sub(&r), None);
+                prop_assert_eq!(r.mul(&scalar(&a)), None);
+            }
+        }
+    }
+
+    #[test]
+    fn too_many_points_none() {
+        let scalars = [Scalar::default(); MAX_MULTISCALAR_MUL_LEN + 1];
+        let points = [EdwardsPoint(ED25519_BASEPOINT_POINT.compress().to_bytes());
+            MAX_MULTISCALAR_MUL_LEN + 1];
+        assert_eq!(EdwardsPoint::multiscalar_mul(&scalars, &points), None);
+        assert!(EdwardsPoint::multiscalar_mul(
+            &scalars[..MAX_MULTISCALAR_MUL_LEN],
+            &points[..MAX_MULTISCALAR_MUL_LEN]
+        )
+        .is_some());
+    }
+}
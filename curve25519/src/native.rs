@@ -0,0 +1,63 @@
+//! Native fallbacks of the curve25519 syscalls for use off-chain,
+//! same as agave's syscall impls
+
+macro_rules! native_curve {
+    ($mod:ident, $Point:ident, $Compressed:ident) => {
+        pub mod $mod {
+            use curve25519_dalek::{
+                scalar::Scalar as DalekScalar, traits::Identity, $Point as DalekPoint,
+                $mod::$Compressed,
+            };
+
+            use crate::{$Point, Scalar, ADD, MUL, SUB};
+
+            #[inline]
+            fn decompress(point: &$Point) -> Option<DalekPoint> {
+                $Compressed(point.0).decompress()
+            }
+
+            #[inline]
+            fn compress(point: &DalekPoint) -> $Point {
+                $Point(point.compress().to_bytes())
+            }
+
+            #[inline]
+            fn scalar(bytes: &[u8; 32]) -> Option<DalekScalar> {
+                DalekScalar::from_canonical_bytes(*bytes).into()
+            }
+
+            #[inline]
+            pub fn validate(point: &$Point) -> bool {
+                decompress(point).is_some()
+            }
+
+            /// For [`MUL`], `left` is the scalar
+            #[inline]
+            pub fn group_op(op: u64, left: &[u8; 32], right: &$Point) -> Option<$Point> {
+                let right = decompress(right)?;
+                let res = match op {
+                    ADD => decompress(&$Point(*left))? + right,
+                    SUB => decompress(&$Point(*left))? - right,
+                    MUL => scalar(left)? * right,
+                    _ => return None,
+                };
+                Some(compress(&res))
+            }
+
+            #[inline]
+            pub fn multiscalar_mul(scalars: &[Scalar], points: &[$Point]) -> Option<$Point> {
+                let res = scalars
+                    .iter()
+                    .zip(points)
+                    .try_fold(<DalekPoint>::identity(), |acc, (s, p)| {
+                        Some(acc + scalar(&s.0)? * decompress(p)?)
+                    })?;
+                Some(compress(&res))
+            }
+        }
+    };
+}
+
+native_curve!(edwards, EdwardsPoint, CompressedEdwardsY);
+
+native_curve!(ristretto, RistrettoPoint, CompressedRistretto);
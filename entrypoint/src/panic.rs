@@ -12,6 +12,37 @@ macro_rules! default_panic_handler {
     };
 }
 
+/// Panic handler that logs the panic location and message.
+///
+/// Use with [`crate::program_entrypoint`] in place of [`crate::entrypoint`].
+///
+/// The location is formatted without `core::fmt`, which is only pulled in
+/// if the program panics with any formatted messages, at the cost of binsize.
+#[macro_export]
+macro_rules! detailed_panic_handler {
+    () => {
+        /// Detailed panic handler.
+        #[cfg(target_os = "solana")]
+        #[no_mangle]
+        fn custom_panic(info: &core::panic::PanicInfo<'_>) {
+            $crate::panic::log_panic_detailed(info);
+        }
+    };
+}
+
+/// Panic handler that logs nothing, for minimum binsize.
+///
+/// Use with [`crate::program_entrypoint`] in place of [`crate::entrypoint`].
+#[macro_export]
+macro_rules! abort_panic_handler {
+    () => {
+        /// Abort panic handler.
+        #[cfg(target_os = "solana")]
+        #[no_mangle]
+        fn custom_panic(_info: &core::panic::PanicInfo<'_>) {}
+    };
+}
+
 #[inline(always)]
 pub fn log_panic(info: &PanicInfo<'_>) {
     #[cfg(target_os = "solana")]
@@ -32,3 +63,46 @@ pub fn log_panic(info: &PanicInfo<'_>) {
         unreachable!()
     }
 }
+
+/// Capacity of the stack buffer used by [`log_panic_detailed`].
+///
+/// Longer panic logs are truncated.
+pub const PANIC_LOG_BUF_LEN: usize = 256;
+
+/// Logs `{file}:{line}:{column}: {message}` to an individual line
+/// without allocating, truncated to [`PANIC_LOG_BUF_LEN`].
+#[inline(always)]
+pub fn log_panic_detailed(info: &PanicInfo<'_>) {
+    #[cfg(target_os = "solana")]
+    {
+        use core::fmt::Write;
+        use jiminy_log::{LogArg, LogBuf};
+
+        let mut buf = LogBuf::<PANIC_LOG_BUF_LEN>::new();
+        if let Some(l) = info.location() {
+            buf.push_str(l.file());
+            buf.push_str(":");
+            l.line().write_to(&mut buf);
+            buf.push_str(":");
+            l.column().write_to(&mut buf);
+            buf.push_str(": ");
+        }
+        let msg = info.message();
+        match msg.as_str() {
+            // skip fmt machinery for messages without args
+            Some(s) => {
+                buf.push_str(s);
+            }
+            None => {
+                let _ = write!(buf, "{msg}");
+            }
+        }
+        buf.log();
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        core::hint::black_box(info);
+        unreachable!()
+    }
+}
@@ -0,0 +1,59 @@
+//! Current epoch's delegated stake lookups via the `sol_get_epoch_stake` syscall.
+
+#![cfg_attr(not(test), no_std)]
+#![allow(unexpected_cfgs)]
+
+/// Returns the total stake, in lamports, delegated to the vote account at `vote`
+/// for the current epoch.
+///
+/// Returns 0 if `vote` is not a vote account or has no delegated stake.
+///
+/// Off-chain, there are no vote accounts, so this always returns 0.
+#[inline]
+pub fn epoch_stake(vote: &[u8; 32]) -> u64 {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        jiminy_syscall::sol_get_epoch_stake(vote.as_ptr())
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        core::hint::black_box(vote);
+        0
+    }
+}
+
+/// Returns the total stake, in lamports, delegated to all vote accounts
+/// for the current epoch.
+///
+/// Off-chain, there are no vote accounts, so this always returns 0.
+#[inline]
+pub fn total_epoch_stake() -> u64 {
+    // null vote address returns the total instead
+    #[cfg(target_os = "solana")]
+    unsafe {
+        jiminy_syscall::sol_get_epoch_stake(core::ptr::null())
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn total_epoch_stake_off_chain_zero() {
+        assert_eq!(total_epoch_stake(), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn epoch_stake_off_chain_zero(vote: [u8; 32]) {
+            prop_assert_eq!(epoch_stake(&vote), 0);
+        }
+    }
+}
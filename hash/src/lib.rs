@@ -0,0 +1,162 @@
+//! Hashing via the `sol_sha256`, `sol_keccak256` and `sol_blake3` syscalls.
+//!
+//! All functions take a slice of byte slices that are hashed as if they were concatenated,
+//! which is the format the syscalls expect.
+//!
+//! Off-chain, these fall back to the [`sha2`], [`sha3`] and [`blake3`] crates for testing.
+
+#![cfg_attr(not(test), no_std)]
+#![allow(unexpected_cfgs)]
+
+use core::mem::MaybeUninit;
+
+mod native;
+
+pub const HASH_LEN: usize = 32;
+
+pub type Hash = [u8; HASH_LEN];
+
+macro_rules! hash_fns {
+    (
+        $(#[$attr:meta])*
+        $name:ident,
+        $name_to:ident,
+        $syscall:ident
+    ) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $name(vals: &[&[u8]]) -> Hash {
+            let mut hash = MaybeUninit::uninit();
+            $name_to(vals, &mut hash);
+            unsafe { hash.assume_init() }
+        }
+
+        #[doc = concat!("This is potentially more compute-efficient than [`", stringify!($name), "`] by using out-pointers.")]
+        ///
+        /// The compiler has proven to be unable to optimize away the move/copy in
+        /// `MaybeUninit::assume_init()` in many cases, especially when the returned value is
+        /// only dropped at entrypoint exit.
+        ///
+        /// A memory leak can potentially occur if the initialized value in the MaybeUninits
+        /// are not dropped, but [u8; 32] is Copy so its fine
+        #[inline]
+        pub fn $name_to<'dst>(vals: &[&[u8]], hash: &'dst mut MaybeUninit<Hash>) -> &'dst mut Hash {
+            #[cfg(target_os = "solana")]
+            {
+                // syscall always returns 0, failures abort the program
+                unsafe {
+                    jiminy_syscall::$syscall(
+                        vals.as_ptr().cast(),
+                        vals.len() as u64,
+                        hash.as_mut_ptr().cast(),
+                    );
+                }
+            }
+
+            #[cfg(not(target_os = "solana"))]
+            {
+                hash.write(native::$name(vals));
+            }
+
+            unsafe { hash.assume_init_mut() }
+        }
+    };
+}
+
+hash_fns!(
+    /// SHA-256 hash of the concatenation of `vals`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiminy_hash::sha256;
+    ///
+    /// assert_eq!(sha256(&[b"hello ", b"world"]), sha256(&[b"hello world"]));
+    /// ```
+    sha256,
+    sha256_to,
+    sol_sha256
+);
+
+hash_fns!(
+    /// Keccak-256 hash of the concatenation of `vals`
+    ///
+    /// This is the original keccak used by ethereum, not the NIST-standardized SHA3-256.
+    keccak256,
+    keccak256_to,
+    sol_keccak256
+);
+
+hash_fns!(
+    /// BLAKE3 hash of the concatenation of `vals`
+    blake3,
+    blake3_to,
+    sol_blake3
+);
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    type HashFn = fn(&[&[u8]]) -> Hash;
+
+    type HashToFn = for<'a> fn(&[&[u8]], &'a mut MaybeUninit<Hash>) -> &'a mut Hash;
+
+    /// (hash fn, hash of empty input in hex)
+    const EMPTY_HASHES: [(HashFn, &str); 3] = [
+        (
+            sha256,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        (
+            keccak256,
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        ),
+        (
+            blake3,
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262",
+        ),
+    ];
+
+    fn hex(hash: &Hash) -> String {
+        hash.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn empty_known_vectors() {
+        for (f, expected) in EMPTY_HASHES {
+            assert_eq!(hex(&f(&[])), expected);
+            assert_eq!(hex(&f(&[&[]])), expected);
+            assert_eq!(hex(&f(&[&[], &[]])), expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn multi_slice_eq_concat(vals in vec(vec(any::<u8>(), 0..64), 0..8)) {
+            let slices: Vec<&[u8]> = vals.iter().map(|v| v.as_slice()).collect();
+            let concat = vals.concat();
+            for (f, _) in EMPTY_HASHES {
+                prop_assert_eq!(f(&slices), f(&[&concat]));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_eq_non_to(vals in vec(vec(any::<u8>(), 0..64), 0..8)) {
+            let slices: Vec<&[u8]> = vals.iter().map(|v| v.as_slice()).collect();
+            let fns: [(HashFn, HashToFn); 3] = [
+                (sha256, sha256_to),
+                (keccak256, keccak256_to),
+                (blake3, blake3_to),
+            ];
+            for (f, f_to) in fns {
+                let mut hash = MaybeUninit::uninit();
+                prop_assert_eq!(*f_to(&slices, &mut hash), f(&slices));
+            }
+        }
+    }
+}
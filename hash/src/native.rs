@@ -0,0 +1,30 @@
+//! Native fallbacks of the hashing syscalls for use off-chain
+
+#![cfg(not(target_os = "solana"))]
+
+use sha2::Digest;
+
+use crate::Hash;
+
+#[inline]
+pub fn sha256(vals: &[&[u8]]) -> Hash {
+    let mut hasher = sha2::Sha256::new();
+    vals.iter().for_each(|v| hasher.update(v));
+    hasher.finalize().into()
+}
+
+#[inline]
+pub fn keccak256(vals: &[&[u8]]) -> Hash {
+    let mut hasher = sha3::Keccak256::new();
+    vals.iter().for_each(|v| hasher.update(v));
+    hasher.finalize().into()
+}
+
+#[inline]
+pub fn blake3(vals: &[&[u8]]) -> Hash {
+    let mut hasher = blake3::Hasher::new();
+    vals.iter().for_each(|v| {
+        hasher.update(v);
+    });
+    *hasher.finalize().as_bytes()
+}
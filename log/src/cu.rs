@@ -0,0 +1,150 @@
+/// Returns the compute units remaining.
+///
+/// Returns 0 if not called from within the VM
+#[inline]
+pub fn remaining_compute_units() -> u64 {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        jiminy_syscall::sol_remaining_compute_units()
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    0
+}
+
+/// Guard that records the compute units remaining on creation
+/// and logs the compute units consumed since then on drop.
+///
+/// Compiles to nothing unless the `cu-profile` feature is enabled,
+/// so these can be left in production code.
+///
+/// The consumed compute units include the cost of one
+/// `sol_remaining_compute_units` syscall.
+///
+/// # Example
+///
+/// ```rust
+/// use jiminy_log::CuScope;
+///
+/// {
+///     let _scope = CuScope::new("deserialize");
+///     // ...
+/// }
+/// ```
+///
+/// will print something like
+///
+/// ```md
+/// Program log: deserialize: 1234 CUs
+/// ```
+///
+/// Labels longer than [`CU_SCOPE_MAX_LABEL_LEN`] bytes are truncated,
+/// so the consumed compute units are always logged.
+#[must_use = "CuScope logs when dropped, binding it to `_` drops it immediately"]
+#[derive(Debug)]
+pub struct CuScope<'a> {
+    #[cfg(feature = "cu-profile")]
+    label: &'a str,
+
+    #[cfg(feature = "cu-profile")]
+    start: u64,
+
+    #[cfg(not(feature = "cu-profile"))]
+    _label: core::marker::PhantomData<&'a str>,
+}
+
+pub const CU_SCOPE_MAX_LABEL_LEN: usize = 64;
+
+impl<'a> CuScope<'a> {
+    #[inline(always)]
+    pub fn new(label: &'a str) -> Self {
+        #[cfg(feature = "cu-profile")]
+        {
+            Self {
+                label,
+                start: remaining_compute_units(),
+            }
+        }
+
+        #[cfg(not(feature = "cu-profile"))]
+        {
+            let _ = label;
+            Self {
+                _label: core::marker::PhantomData,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "cu-profile")]
+impl Drop for CuScope<'_> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        let consumed = self.start.saturating_sub(remaining_compute_units());
+        fmt_cus_consumed(self.label, consumed).log();
+    }
+}
+
+/// label + ": " + u64::MAX in decimal + " CUs"
+#[cfg(any(test, feature = "cu-profile"))]
+const CU_SCOPE_LOG_BUF_LEN: usize = CU_SCOPE_MAX_LABEL_LEN + 2 + 20 + 4;
+
+/// Formats `"{label}: {consumed} CUs"`, with `label` truncated to [`CU_SCOPE_MAX_LABEL_LEN`]
+#[cfg(any(test, feature = "cu-profile"))]
+#[inline(always)]
+fn fmt_cus_consumed(label: &str, consumed: u64) -> crate::LogBuf<CU_SCOPE_LOG_BUF_LEN> {
+    let mut buf = crate::LogBuf::<CU_SCOPE_LOG_BUF_LEN>::new();
+    // truncate at a char boundary, runtime rejects invalid utf8
+    let mut label_len = label.len().min(CU_SCOPE_MAX_LABEL_LEN);
+    while !label.is_char_boundary(label_len) {
+        label_len -= 1;
+    }
+    buf.push_str(&label[..label_len]);
+    buf.push_str(": ");
+    crate::LogArg::write_to(&consumed, &mut buf);
+    buf.push_str(" CUs");
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// `s` truncated to at most `n` bytes at a char boundary
+    fn truncate(s: &str, n: usize) -> &str {
+        let mut n = s.len().min(n);
+        while !s.is_char_boundary(n) {
+            n -= 1;
+        }
+        &s[..n]
+    }
+
+    #[test]
+    fn long_label_keeps_cus() {
+        let label = "a".repeat(300);
+        let buf = fmt_cus_consumed(&label, u64::MAX);
+        assert_eq!(
+            buf.as_str(),
+            format!("{}: {} CUs", &label[..CU_SCOPE_MAX_LABEL_LEN], u64::MAX)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn fmt_cus_consumed_eq_format(label in ".{0,300}", consumed: u64) {
+            let buf = fmt_cus_consumed(&label, consumed);
+            let label = truncate(&label, CU_SCOPE_MAX_LABEL_LEN);
+            prop_assert_eq!(buf.as_str(), format!("{label}: {consumed} CUs"));
+        }
+    }
+
+    #[cfg(feature = "cu-profile")]
+    #[test]
+    fn cu_scope_drop_off_chain() {
+        let scope = CuScope::new("scope");
+        assert_eq!(scope.start, 0);
+        drop(scope);
+    }
+}
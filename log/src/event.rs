@@ -0,0 +1,316 @@
+use core::{fmt::Display, mem::MaybeUninit};
+
+/// Length of an [`Event`]'s discriminator
+pub const EVENT_DISCRIMINATOR_LEN: usize = 8;
+
+/// Max length of an event logged with [`emit_anchor`] or [`crate::emit_anchor!`],
+/// including the discriminator.
+///
+/// This is the size of the stack buffer the event is concatenated into.
+pub const EVENT_BUF_LEN: usize = 1024;
+
+/// Anchor's event discriminator: the first 8 bytes of `sha256("event:{name}")`
+///
+/// # Example
+///
+/// ```
+/// use jiminy_log::event_discriminator;
+///
+/// const SWAP_DISCRIMINATOR: [u8; 8] = event_discriminator("Swap");
+/// ```
+#[inline]
+pub const fn event_discriminator(name: &str) -> [u8; EVENT_DISCRIMINATOR_LEN] {
+    let hash = const_crypto::sha2::Sha256::new()
+        .update(b"event:")
+        .update(name.as_bytes())
+        .finalize();
+    let mut res = [0u8; EVENT_DISCRIMINATOR_LEN];
+    let mut i = 0;
+    while i < EVENT_DISCRIMINATOR_LEN {
+        res[i] = hash[i];
+        i += 1;
+    }
+    res
+}
+
+/// A structured event that can be logged with [`emit`].
+///
+/// An event is framed like an Anchor event: [`Event::DISCRIMINATOR`] followed by
+/// the borsh-serialized event.
+pub trait Event {
+    /// Use [`event_discriminator`] for the Anchor event discriminator
+    const DISCRIMINATOR: [u8; EVENT_DISCRIMINATOR_LEN];
+
+    /// The borsh-serialized event, excluding the discriminator.
+    ///
+    /// For zero-copy `#[repr(C)]` types with no padding and only
+    /// little-endian primitive and byte array fields, this is simply the struct's bytes.
+    fn body(&self) -> &[u8];
+}
+
+/// Logs an [`Event`] to an individual line.
+///
+/// The discriminator and body are logged as 2 separate fields of a single `sol_log_data` call,
+/// to avoid concatenating them in a separate buffer.
+/// The runtime prints each field base64-encoded separated by a single space,
+/// so the fields must be concatenated after base64-decoding to obtain the Anchor-framed event data.
+///
+/// Use [`emit_anchor`] instead if the event must be parseable by Anchor's `EventParser`,
+/// which expects a single field.
+///
+/// # Example
+///
+/// ```
+/// use jiminy_log::{emit, event_discriminator, Event};
+///
+/// #[repr(C)]
+/// struct Swap {
+///     amt_in: [u8; 8],
+///     amt_out: [u8; 8],
+/// }
+///
+/// impl Event for Swap {
+///     const DISCRIMINATOR: [u8; 8] = event_discriminator("Swap");
+///
+///     fn body(&self) -> &[u8] {
+///         unsafe {
+///             core::slice::from_raw_parts(
+///                 (self as *const Self).cast(),
+///                 core::mem::size_of::<Self>(),
+///             )
+///         }
+///     }
+/// }
+///
+/// emit(&Swap {
+///     amt_in: 1u64.to_le_bytes(),
+///     amt_out: 2u64.to_le_bytes(),
+/// });
+/// ```
+///
+/// will output
+///
+/// ```md
+/// Program data: UWzjvs3QCsQ= AQAAAAAAAAACAAAAAAAAAA==
+/// ```
+#[inline]
+pub fn emit<E: Event + ?Sized>(event: &E) {
+    crate::sol_log_data(&[&E::DISCRIMINATOR, event.body()]);
+}
+
+/// Logs an [`Event`], or a discriminator followed by multiple body slices,
+/// to an individual line with a single `sol_log_data` call.
+///
+/// The multiple body slices form allows logging events with variable-length
+/// fields without concatenating them first. The slices must together make up
+/// the borsh-serialized event.
+///
+/// # Example
+///
+/// ```
+/// use jiminy_log::{emit, event_discriminator};
+///
+/// const MEMO_DISCRIMINATOR: [u8; 8] = event_discriminator("Memo");
+///
+/// let memo = b"hello";
+/// // borsh Vec<u8>: u32 len prefix followed by the bytes
+/// emit!(MEMO_DISCRIMINATOR; &(memo.len() as u32).to_le_bytes(), memo);
+/// ```
+#[macro_export]
+macro_rules! emit {
+    ($event:expr $(,)?) => {
+        $crate::emit(&$event)
+    };
+    ($discm:expr; $($body:expr),+ $(,)?) => {
+        $crate::sol_log_data(&[&$discm, $($body),+])
+    };
+}
+
+/// The event is longer than [`EVENT_BUF_LEN`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventTooLongError;
+
+impl Display for EventTooLongError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("EventTooLongError")
+    }
+}
+
+impl core::error::Error for EventTooLongError {}
+
+/// Logs an [`Event`] to an individual line as a single base64 field,
+/// the format Anchor's `EventParser` expects.
+///
+/// Unlike [`emit`], this first concatenates the discriminator and body
+/// into a stack buffer of [`EVENT_BUF_LEN`], which costs CUs and stack space.
+///
+/// Logs nothing and errs if the event is longer than [`EVENT_BUF_LEN`].
+///
+/// # Example
+///
+/// With the `Swap` event from [`emit`]'s example,
+///
+/// ```md
+/// Program data: UWzjvs3QCsQBAAAAAAAAAAIAAAAAAAAA
+/// ```
+#[inline]
+pub fn emit_anchor<E: Event + ?Sized>(event: &E) -> Result<(), EventTooLongError> {
+    __emit_anchor_slices(&[&E::DISCRIMINATOR, event.body()])
+}
+
+/// [`crate::emit!`], but logged as a single base64 field like [`emit_anchor`].
+///
+/// Evaluates to `Err(EventTooLongError)`, logging nothing,
+/// if the event is longer than [`EVENT_BUF_LEN`].
+///
+/// # Example
+///
+/// ```
+/// use jiminy_log::{emit_anchor, event_discriminator};
+///
+/// const MEMO_DISCRIMINATOR: [u8; 8] = event_discriminator("Memo");
+///
+/// let memo = b"hello";
+/// emit_anchor!(MEMO_DISCRIMINATOR; &(memo.len() as u32).to_le_bytes(), memo).unwrap();
+/// ```
+///
+/// will output
+///
+/// ```md
+/// Program data: CjCuEmUwMk0FAAAAaGVsbG8=
+/// ```
+#[macro_export]
+macro_rules! emit_anchor {
+    ($event:expr $(,)?) => {
+        $crate::emit_anchor(&$event)
+    };
+    ($discm:expr; $($body:expr),+ $(,)?) => {
+        $crate::__emit_anchor_slices(&[&$discm, $($body),+])
+    };
+}
+
+/// Concatenates `slices` and logs them as a single `sol_log_data` field
+#[doc(hidden)]
+#[inline]
+pub fn __emit_anchor_slices(slices: &[&[u8]]) -> Result<(), EventTooLongError> {
+    let mut buf = [const { MaybeUninit::uninit() }; EVENT_BUF_LEN];
+    crate::sol_log_data(&[concat_to(&mut buf, slices)?]);
+    Ok(())
+}
+
+/// Errs without writing anything if `slices` are together longer than `buf`
+#[inline(always)]
+fn concat_to<'a>(
+    buf: &'a mut [MaybeUninit<u8>],
+    slices: &[&[u8]],
+) -> Result<&'a [u8], EventTooLongError> {
+    let total = slices
+        .iter()
+        .try_fold(0usize, |len, s| len.checked_add(s.len()))
+        .ok_or(EventTooLongError)?;
+    if total > buf.len() {
+        return Err(EventTooLongError);
+    }
+    let len = slices.iter().fold(0, |start, s| {
+        // safety: total fits in buf as checked above,
+        // src and dst cannot overlap since buf is &mut
+        unsafe {
+            core::ptr::copy_nonoverlapping(s.as_ptr(), buf.as_mut_ptr().add(start).cast(), s.len());
+        }
+        start + s.len()
+    });
+    // safety: buf[..len] initialized above
+    Ok(unsafe { core::slice::from_raw_parts(buf.as_ptr().cast(), len) })
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn event_discriminator_matches_anchor() {
+        // anchor computes event discriminators as sha256("event:{name}")[..8]
+        use sha2::Digest;
+
+        for name in ["Swap", "Deposit", "MyEvent", ""] {
+            let hash = sha2::Sha256::digest(format!("event:{name}"));
+            assert_eq!(event_discriminator(name), hash[..8]);
+        }
+    }
+
+    #[test]
+    fn emit_compiles() {
+        struct Ev([u8; 4]);
+
+        impl Event for Ev {
+            const DISCRIMINATOR: [u8; 8] = event_discriminator("Ev");
+
+            fn body(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        let ev = Ev([1, 2, 3, 4]);
+        emit(&ev);
+        emit!(ev);
+        emit!(Ev::DISCRIMINATOR; &[1, 2], &[3, 4]);
+        assert_eq!(emit_anchor(&ev), Ok(()));
+        assert_eq!(emit_anchor!(ev), Ok(()));
+        assert_eq!(emit_anchor!(Ev::DISCRIMINATOR; &[1, 2], &[3, 4]), Ok(()));
+    }
+
+    proptest! {
+        #[test]
+        fn concat_to_eq_concat(
+            slices in proptest::collection::vec(proptest::collection::vec(any::<u8>(), 0..512), 0..4),
+        ) {
+            let mut buf = [const { MaybeUninit::uninit() }; EVENT_BUF_LEN];
+            let slices: Vec<_> = slices.iter().map(Vec::as_slice).collect();
+            let expected = slices.concat();
+            let res = concat_to(&mut buf, &slices);
+            if expected.len() <= EVENT_BUF_LEN {
+                prop_assert_eq!(res, Ok(expected.as_slice()));
+            } else {
+                prop_assert_eq!(res, Err(EventTooLongError));
+            }
+        }
+    }
+
+    #[test]
+    fn concat_to_boundary() {
+        let mut buf = [const { MaybeUninit::uninit() }; EVENT_BUF_LEN];
+        let discm = [2; EVENT_DISCRIMINATOR_LEN];
+        let body = [1; EVENT_BUF_LEN - EVENT_DISCRIMINATOR_LEN + 1];
+        let [fits, too_long] = [&body[1..], &body[..]];
+
+        assert_eq!(
+            concat_to(&mut buf, &[&discm, fits]).map(<[u8]>::len),
+            Ok(EVENT_BUF_LEN)
+        );
+        assert_eq!(
+            concat_to(&mut buf, &[&discm, too_long]),
+            Err(EventTooLongError)
+        );
+    }
+
+    #[test]
+    fn emit_anchor_too_long_errs() {
+        struct Big([u8; EVENT_BUF_LEN]);
+
+        impl Event for Big {
+            const DISCRIMINATOR: [u8; 8] = event_discriminator("Big");
+
+            fn body(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        let big = Big([0; EVENT_BUF_LEN]);
+        assert_eq!(emit_anchor(&big), Err(EventTooLongError));
+        // emit has no length limit
+        emit(&big);
+    }
+}
@@ -0,0 +1,443 @@
+use core::mem::MaybeUninit;
+
+/// Capacity of the stack buffer used by [`crate::log!`].
+///
+/// Output exceeding this is truncated.
+pub const LOG_BUF_LEN: usize = 256;
+
+/// Fixed-capacity stack buffer that [`LogArg`]s are written to.
+///
+/// Writes that exceed capacity are truncated. Contents are always valid utf8.
+#[derive(Debug, Clone, Copy)]
+pub struct LogBuf<const N: usize = LOG_BUF_LEN> {
+    len: usize,
+    buf: [MaybeUninit<u8>; N],
+}
+
+impl<const N: usize> LogBuf<N> {
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            buf: [const { MaybeUninit::uninit() }; N],
+        }
+    }
+}
+
+impl<const N: usize> Default for LogBuf<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Accessors
+impl<const N: usize> LogBuf<N> {
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        N - self.len
+    }
+
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        // safety: buf[..len] is initialized with valid utf8
+        unsafe {
+            core::str::from_utf8_unchecked(core::slice::from_raw_parts(
+                self.buf.as_ptr().cast(),
+                self.len,
+            ))
+        }
+    }
+}
+
+// Mutators
+impl<const N: usize> LogBuf<N> {
+    /// Appends `s`, truncated at a char boundary to fit if required
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        let mut n = s.len().min(self.remaining());
+        while !s.is_char_boundary(n) {
+            n -= 1;
+        }
+        self.push_utf8_unchecked(&s.as_bytes()[..n]);
+    }
+
+    /// Appends ASCII bytes, truncated to fit if required
+    #[inline]
+    fn push_ascii(&mut self, ascii: &[u8]) {
+        let n = ascii.len().min(self.remaining());
+        self.push_utf8_unchecked(&ascii[..n]);
+    }
+
+    /// `bytes` must be valid utf8 and fit in remaining capacity
+    #[inline]
+    fn push_utf8_unchecked(&mut self, bytes: &[u8]) {
+        self.buf[self.len..self.len + bytes.len()]
+            .iter_mut()
+            .zip(bytes)
+            .for_each(|(d, s)| {
+                d.write(*s);
+            });
+        self.len += bytes.len();
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> LogBuf<N> {
+    /// Logs the contents of this buffer to an individual line
+    #[inline]
+    pub fn log(&self) {
+        crate::sol_log(self.as_str());
+    }
+}
+
+/// For formatting with `core::fmt` where [`LogArg`] doesn't suffice,
+/// at the cost of pulling in the `core::fmt` machinery.
+impl<const N: usize> core::fmt::Write for LogBuf<N> {
+    /// Errs once `s` is truncated, to stop formatting early
+    #[inline]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let fits = s.len() <= self.remaining();
+        self.push_str(s);
+        if fits {
+            Ok(())
+        } else {
+            Err(core::fmt::Error)
+        }
+    }
+}
+
+/// A value that can be formatted by [`crate::log!`]
+pub trait LogArg {
+    fn write_to<const N: usize>(&self, buf: &mut LogBuf<N>);
+}
+
+impl<T: LogArg + ?Sized> LogArg for &T {
+    #[inline(always)]
+    fn write_to<const N: usize>(&self, buf: &mut LogBuf<N>) {
+        (**self).write_to(buf)
+    }
+}
+
+impl LogArg for str {
+    #[inline(always)]
+    fn write_to<const N: usize>(&self, buf: &mut LogBuf<N>) {
+        buf.push_str(self)
+    }
+}
+
+impl LogArg for bool {
+    #[inline(always)]
+    fn write_to<const N: usize>(&self, buf: &mut LogBuf<N>) {
+        buf.push_ascii(if *self { b"true" } else { b"false" })
+    }
+}
+
+/// Formatted in base58
+impl LogArg for [u8; 32] {
+    #[inline]
+    fn write_to<const N: usize>(&self, buf: &mut LogBuf<N>) {
+        let mut b58 = [0u8; 44];
+        let len = five8::encode_32(self, &mut b58);
+        buf.push_ascii(&b58[..len as usize])
+    }
+}
+
+/// u64::MAX and i64::MIN are 20 chars long in decimal
+const MAX_DEC_LEN: usize = 20;
+
+#[inline]
+fn write_dec<const N: usize>(buf: &mut LogBuf<N>, mut n: u64) {
+    let mut digits = [0u8; MAX_DEC_LEN];
+    let mut start = MAX_DEC_LEN;
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    buf.push_ascii(&digits[start..])
+}
+
+macro_rules! impl_log_arg_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl LogArg for $t {
+                #[inline]
+                fn write_to<const N: usize>(&self, buf: &mut LogBuf<N>) {
+                    write_dec(buf, *self as u64)
+                }
+            }
+        )*
+    };
+}
+
+impl_log_arg_unsigned!(u8, u16, u32, u64, usize);
+
+macro_rules! impl_log_arg_signed {
+    ($($t:ty),*) => {
+        $(
+            impl LogArg for $t {
+                #[inline]
+                fn write_to<const N: usize>(&self, buf: &mut LogBuf<N>) {
+                    if *self < 0 {
+                        buf.push_ascii(b"-");
+                    }
+                    write_dec(buf, self.unsigned_abs() as u64)
+                }
+            }
+        )*
+    };
+}
+
+impl_log_arg_signed!(i8, i16, i32, i64, isize);
+
+/// Splits a [`crate::log!`] format string into the `N` pieces around its `{}` placeholders.
+///
+/// Panics, and hence fails compilation when used in const context, if:
+/// - the format string contains any `{` or `}` not part of a `{}`
+/// - the format string does not have exactly `N - 1` placeholders
+#[doc(hidden)]
+pub const fn __split_fmt<const N: usize>(fmt: &'static str) -> [&'static str; N] {
+    let bytes = fmt.as_bytes();
+    let mut res = [""; N];
+    let mut n = 0;
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'{' => {
+                if i + 1 >= bytes.len() || bytes[i + 1] != b'}' {
+                    panic!("log!: only {{}} placeholders are supported");
+                }
+                if n + 1 >= N {
+                    panic!("log!: more {{}} placeholders than args");
+                }
+                res[n] = const_substr(bytes, start, i);
+                n += 1;
+                i += 2;
+                start = i;
+            }
+            b'}' => panic!("log!: unmatched }}"),
+            _ => i += 1,
+        }
+    }
+    if n + 1 != N {
+        panic!("log!: fewer {{}} placeholders than args");
+    }
+    res[n] = const_substr(bytes, start, bytes.len());
+    res
+}
+
+/// `bytes[start..end]` must be valid utf8
+const fn const_substr(bytes: &'static [u8], start: usize, end: usize) -> &'static str {
+    let (head, _) = bytes.split_at(end);
+    let (_, sub) = head.split_at(start);
+    // safety: fmt is split at ASCII `{` and `}`, which are always char boundaries
+    unsafe { core::str::from_utf8_unchecked(sub) }
+}
+
+/// Allocation-free alternative to `msg!`.
+///
+/// Formats args into a [`LogBuf`] of [`LOG_BUF_LEN`] on the stack and logs
+/// the result to an individual line with a single `sol_log_` syscall.
+///
+/// The format string must be a string literal where each arg is substituted
+/// into a `{}`. No other formatting specifiers or `{{`/`}}` escapes are supported.
+/// This and the number of args are checked at compile-time.
+///
+/// Args can be any [`LogArg`]:
+/// - integers up to 64 bits, in decimal
+/// - `bool`
+/// - `&str`
+/// - `[u8; 32]` pubkeys, in base58
+///
+/// Output longer than [`LOG_BUF_LEN`] is truncated.
+///
+/// For purely numeric tracing, [`crate::log_64!`] is much cheaper.
+///
+/// # Example
+///
+/// ```
+/// use jiminy_log::log;
+///
+/// let amt = 1_000u64;
+/// let recipient = [0u8; 32];
+/// log!("transfer {} to {}: {}", amt, recipient, true);
+/// ```
+///
+/// will print
+///
+/// ```md
+/// Program log: transfer 1000 to 11111111111111111111111111111111: true
+/// ```
+///
+/// Mismatched placeholders fail compilation, including when there are no args,
+/// and for the [`crate::Level`] macros:
+///
+/// ```compile_fail
+/// jiminy_log::log!("missing arg {}");
+/// ```
+///
+/// ```compile_fail
+/// jiminy_log::info!("missing arg {}");
+/// ```
+///
+/// ```compile_fail
+/// jiminy_log::log!("extra arg", 1u8);
+/// ```
+#[macro_export]
+macro_rules! log {
+    ($fmt:literal $(,)?) => {
+        $crate::log!(@fmt $fmt)
+    };
+    ($fmt:literal, $($arg:expr),+ $(,)?) => {
+        $crate::log!(@fmt $fmt, $($arg),+)
+    };
+
+    // $fmt can be any const &'static str expr e.g. concat!()
+    (@fmt $fmt:expr) => {{
+        const _: [&str; 1] = $crate::__split_fmt($fmt);
+        $crate::sol_log($fmt)
+    }};
+    (@fmt $fmt:expr, $($arg:expr),+) => {{
+        const PIECES: [&str; 1 + [$(stringify!($arg)),+].len()] = $crate::__split_fmt($fmt);
+        let mut buf = $crate::LogBuf::<{ $crate::LOG_BUF_LEN }>::new();
+        let mut i = 0;
+        $(
+            buf.push_str(PIECES[i]);
+            $crate::LogArg::write_to(&$arg, &mut buf);
+            i += 1;
+        )+
+        buf.push_str(PIECES[i]);
+        buf.log();
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn fmt_arg(arg: impl LogArg) -> String {
+        let mut buf = LogBuf::<LOG_BUF_LEN>::new();
+        arg.write_to(&mut buf);
+        buf.as_str().to_owned()
+    }
+
+    proptest! {
+        #[test]
+        fn ints_eq_std(u: u64, i: i64, u8_: u8, i8_: i8, us: usize, is: isize) {
+            prop_assert_eq!(fmt_arg(u), u.to_string());
+            prop_assert_eq!(fmt_arg(i), i.to_string());
+            prop_assert_eq!(fmt_arg(u8_), u8_.to_string());
+            prop_assert_eq!(fmt_arg(i8_), i8_.to_string());
+            prop_assert_eq!(fmt_arg(us), us.to_string());
+            prop_assert_eq!(fmt_arg(is), is.to_string());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn fmt_write_eq_std_truncated(v: Vec<(i64, String)>) {
+            use core::fmt::Write;
+
+            let expected = format!("{v:?}");
+            let mut buf = LogBuf::<LOG_BUF_LEN>::new();
+            let res = write!(buf, "{v:?}");
+            prop_assert_eq!(res.is_ok(), expected.len() <= LOG_BUF_LEN);
+            prop_assert!(expected.starts_with(buf.as_str()));
+            if res.is_ok() {
+                prop_assert_eq!(buf.as_str(), expected);
+            } else {
+                // only truncated to a char boundary
+                prop_assert!(buf.len() > LOG_BUF_LEN - 4);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn str_bool_eq_std(s: String, b: bool) {
+            prop_assume!(s.len() <= LOG_BUF_LEN);
+            prop_assert_eq!(fmt_arg(s.as_str()), s);
+            prop_assert_eq!(fmt_arg(b), b.to_string());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn pubkey_eq_bs58(pk: [u8; 32]) {
+            prop_assert_eq!(fmt_arg(pk), bs58::encode(pk).into_string());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn truncates_at_char_boundary(s in ".{0,300}", prefix in 0usize..=8) {
+            let mut buf = LogBuf::<LOG_BUF_LEN>::new();
+            buf.push_str(&"a".repeat(prefix));
+            buf.push_str(&s);
+            let res = buf.as_str();
+            prop_assert!(res.len() <= LOG_BUF_LEN);
+            prop_assert!(s.starts_with(&res[prefix..]));
+            if s.len() + prefix > LOG_BUF_LEN {
+                let next_char_len = s[res.len() - prefix..].chars().next().unwrap().len_utf8();
+                prop_assert!(res.len() + next_char_len > LOG_BUF_LEN);
+            }
+        }
+    }
+
+    #[test]
+    fn split_fmt() {
+        const PIECES: [&str; 3] = __split_fmt("swap {} -> {}");
+        assert_eq!(PIECES, ["swap ", " -> ", ""]);
+        const NO_SPACES: [&str; 3] = __split_fmt("{}{}");
+        assert_eq!(NO_SPACES, ["", "", ""]);
+        const NONE: [&str; 1] = __split_fmt("héllo");
+        assert_eq!(NONE, ["héllo"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_fmt_specifier_panics() {
+        __split_fmt::<2>("{:?}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_fmt_too_few_args_panics() {
+        __split_fmt::<2>("{} {}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_fmt_too_many_args_panics() {
+        __split_fmt::<3>("{}");
+    }
+
+    #[test]
+    fn log_compiles() {
+        let pk = [1u8; 32];
+        log!("no args");
+        log!("swap {} -> {} for {}", 1u8, -2i64, pk);
+        log!("{}{}", "str", false,);
+    }
+}
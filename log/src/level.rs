@@ -0,0 +1,194 @@
+/// Log levels, in increasing verbosity.
+///
+/// A level is enabled if its `level-*` crate feature or a more verbose one is enabled,
+/// or if `--cfg jiminy_log_level="<level>"` is set to it or a more verbose one.
+/// Level features are additive: enabling `level-debug` enables `level-info`,
+/// `level-warn` and `level-error` too. No levels are enabled by default.
+///
+/// Logging at disabled levels compiles to nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+/// Most verbose enabled [`Level`], or `None` if all levels are disabled
+pub const MAX_LEVEL: Option<Level> =
+    if cfg!(any(feature = "level-trace", jiminy_log_level = "trace")) {
+        Some(Level::Trace)
+    } else if cfg!(any(feature = "level-debug", jiminy_log_level = "debug")) {
+        Some(Level::Debug)
+    } else if cfg!(any(feature = "level-info", jiminy_log_level = "info")) {
+        Some(Level::Info)
+    } else if cfg!(any(feature = "level-warn", jiminy_log_level = "warn")) {
+        Some(Level::Warn)
+    } else if cfg!(any(feature = "level-error", jiminy_log_level = "error")) {
+        Some(Level::Error)
+    } else {
+        None
+    };
+
+impl Level {
+    #[inline(always)]
+    pub const fn is_enabled(self) -> bool {
+        match MAX_LEVEL {
+            Some(max) => self as u8 <= max as u8,
+            None => false,
+        }
+    }
+
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Error => "ERROR",
+            Self::Warn => "WARN",
+            Self::Info => "INFO",
+            Self::Debug => "DEBUG",
+            Self::Trace => "TRACE",
+        }
+    }
+}
+
+/// [`crate::sol_log_pubkey`] if `level` is enabled
+#[inline(always)]
+pub fn sol_log_pubkey_at(level: Level, pubkey: &[u8; 32]) {
+    if level.is_enabled() {
+        crate::sol_log_pubkey(pubkey);
+    }
+}
+
+/// [`crate::sol_log_slice`] if `level` is enabled
+#[inline(always)]
+pub fn sol_log_slice_at(level: Level, data: &[u8]) {
+    if level.is_enabled() {
+        crate::sol_log_slice(data);
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_at {
+    ($level:ident, $prefix:literal, $fmt:literal $(, $arg:expr)* $(,)?) => {
+        if const { $crate::Level::$level.is_enabled() } {
+            $crate::log!(@fmt concat!($prefix, $fmt) $(, $arg)*);
+        }
+    };
+}
+
+/// [`crate::log!`] prefixed with `ERROR: ` if [`Level::Error`] is enabled.
+///
+/// Args are not evaluated if disabled.
+#[macro_export]
+macro_rules! error {
+    ($($t:tt)*) => {
+        $crate::__log_at!(Error, "ERROR: ", $($t)*)
+    };
+}
+
+/// [`crate::log!`] prefixed with `WARN: ` if [`Level::Warn`] is enabled.
+///
+/// Args are not evaluated if disabled.
+#[macro_export]
+macro_rules! warn {
+    ($($t:tt)*) => {
+        $crate::__log_at!(Warn, "WARN: ", $($t)*)
+    };
+}
+
+/// [`crate::log!`] prefixed with `INFO: ` if [`Level::Info`] is enabled.
+///
+/// Args are not evaluated if disabled.
+#[macro_export]
+macro_rules! info {
+    ($($t:tt)*) => {
+        $crate::__log_at!(Info, "INFO: ", $($t)*)
+    };
+}
+
+/// [`crate::log!`] prefixed with `DEBUG: ` if [`Level::Debug`] is enabled.
+///
+/// Args are not evaluated if disabled.
+///
+/// # Example
+///
+/// ```
+/// use jiminy_log::debug;
+///
+/// let bump = 255u8;
+/// debug!("found bump {}", bump);
+/// ```
+///
+/// will print, only if `level-debug` or `level-trace` is enabled,
+///
+/// ```md
+/// Program log: DEBUG: found bump 255
+/// ```
+#[macro_export]
+macro_rules! debug {
+    ($($t:tt)*) => {
+        $crate::__log_at!(Debug, "DEBUG: ", $($t)*)
+    };
+}
+
+/// [`crate::log!`] prefixed with `TRACE: ` if [`Level::Trace`] is enabled.
+///
+/// Args are not evaluated if disabled.
+#[macro_export]
+macro_rules! trace {
+    ($($t:tt)*) => {
+        $crate::__log_at!(Trace, "TRACE: ", $($t)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [Level; 5] = [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    #[test]
+    fn enabled_levels_are_prefix() {
+        let n_enabled = ALL.iter().take_while(|l| l.is_enabled()).count();
+        assert!(ALL[n_enabled..].iter().all(|l| !l.is_enabled()));
+        assert_eq!(MAX_LEVEL, n_enabled.checked_sub(1).map(|i| ALL[i]));
+    }
+
+    #[test]
+    fn args_evaluated_iff_enabled() {
+        let mut evaluated = [false; 5];
+        error!("{}", {
+            evaluated[0] = true;
+            1u8
+        });
+        warn!("{} {}", 1u8, {
+            evaluated[1] = true;
+            true
+        });
+        info!("{}", {
+            evaluated[2] = true;
+            "info"
+        },);
+        debug!("{}", {
+            evaluated[3] = true;
+            -1i64
+        });
+        trace!("{}", {
+            evaluated[4] = true;
+            [0u8; 32]
+        });
+        assert_eq!(evaluated, ALL.map(Level::is_enabled));
+
+        // no-args form
+        error!("error");
+        trace!("trace");
+    }
+}
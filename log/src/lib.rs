@@ -3,6 +3,18 @@
 #![cfg_attr(all(not(test), not(feature = "std")), no_std)]
 #![allow(unexpected_cfgs)]
 
+mod cu;
+mod event;
+mod fmt;
+mod level;
+mod log64;
+
+pub use cu::*;
+pub use event::*;
+pub use fmt::*;
+pub use level::*;
+pub use log64::*;
+
 /// Print a Rust [format strings][fs] message to the log.
 ///
 /// To print a simple string, DO NOT USE THIS MACRO.
@@ -18,6 +30,7 @@
 ///
 /// Note that Rust's formatting machinery is relatively CPU-intensive
 /// for constrained environments like the Solana VM.
+/// Prefer [`log!`] where possible.
 ///
 /// # Examples
 ///
@@ -107,26 +120,39 @@ pub fn sol_log_cus_remaining() {
 /// ```
 #[inline]
 pub fn sol_log_slice(data: &[u8]) {
+    sol_log_data(&[data]);
+}
+
+/// Logs multiple byte slices, each in base64 format separated by a space,
+/// to an individual line
+///
+/// # Example
+///
+/// ```rust
+/// use jiminy_log::sol_log_data;
+///
+/// sol_log_data(&[&[1, 2, 3, 4], &[5, 6]]);
+/// ```
+///
+/// will output
+///
+/// ```md
+/// Program data: AQIDBA== BQY=
+/// ```
+#[inline]
+pub fn sol_log_data(fields: &[&[u8]]) {
     #[cfg(target_os = "solana")]
     {
-        #[repr(C)]
-        struct ByteSlice {
-            ptr: *const u8,
-            len: u64,
-        }
-
-        let a = ByteSlice {
-            ptr: data.as_ptr(),
-            len: data.len() as u64,
-        };
+        // `&[u8]` has the same layout as the runtime's `{ ptr: *const u8, len: u64 }`
+        // on 64-bit targets
         unsafe {
-            jiminy_syscall::sol_log_data(core::ptr::addr_of!(a).cast(), 1);
+            jiminy_syscall::sol_log_data(fields.as_ptr().cast(), fields.len() as u64);
         }
     }
 
     #[cfg(not(target_os = "solana"))]
     {
-        core::hint::black_box(data);
+        core::hint::black_box(fields);
     }
 }
 
@@ -0,0 +1,156 @@
+/// Maximum number of values that can be logged with a single [`sol_log_64`]
+pub const MAX_LOG_64_VALS: usize = 5;
+
+/// Logs 5 u64s in hex to an individual line
+///
+/// # Example
+///
+/// ```rust
+/// use jiminy_log::sol_log_64;
+///
+/// sol_log_64(1, 2, 3, 4, 255);
+/// ```
+///
+/// will print
+///
+/// ```md
+/// Program log: 0x1, 0x2, 0x3, 0x4, 0xff
+/// ```
+#[inline]
+pub fn sol_log_64(a: u64, b: u64, c: u64, d: u64, e: u64) {
+    #[cfg(target_os = "solana")]
+    {
+        unsafe {
+            jiminy_syscall::sol_log_64_(a, b, c, d, e);
+        }
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        core::hint::black_box((a, b, c, d, e));
+    }
+}
+
+/// Logs up to [`MAX_LOG_64_VALS`] u64s with a single [`sol_log_64`],
+/// padded with trailing zeros.
+///
+/// Fails compilation if `N > MAX_LOG_64_VALS`.
+#[inline(always)]
+pub fn sol_log_u64s<const N: usize>(vals: [u64; N]) {
+    const {
+        assert!(N <= MAX_LOG_64_VALS);
+    }
+    let mut padded = [0u64; MAX_LOG_64_VALS];
+    padded[..N].copy_from_slice(&vals);
+    let [a, b, c, d, e] = padded;
+    sol_log_64(a, b, c, d, e);
+}
+
+/// Logs up to [`MAX_LOG_64_VALS`] i64s with a single [`sol_log_64`],
+/// padded with trailing zeros.
+///
+/// Negative values are printed as their 64-bit two's complement in hex,
+/// e.g. -1 is `0xffffffffffffffff`.
+///
+/// Fails compilation if `N > MAX_LOG_64_VALS`.
+#[inline(always)]
+pub fn sol_log_i64s<const N: usize>(vals: [i64; N]) {
+    sol_log_u64s(vals.map(|v| v as u64));
+}
+
+/// A value that can be logged by [`crate::log_64!`]
+pub trait Log64 {
+    /// Signed integers are sign-extended to 64-bit two's complement
+    fn to_log_64(self) -> u64;
+}
+
+impl Log64 for bool {
+    #[inline(always)]
+    fn to_log_64(self) -> u64 {
+        self as u64
+    }
+}
+
+macro_rules! impl_log_64_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Log64 for $t {
+                #[inline(always)]
+                fn to_log_64(self) -> u64 {
+                    self as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_log_64_unsigned!(u8, u16, u32, u64, usize);
+
+macro_rules! impl_log_64_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Log64 for $t {
+                #[inline(always)]
+                fn to_log_64(self) -> u64 {
+                    self as i64 as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_log_64_signed!(i8, i16, i32, i64, isize);
+
+/// Logs 1 to [`MAX_LOG_64_VALS`] integers or bools in hex to an individual line
+/// with a single `sol_log_64_` syscall, padded with trailing zeros.
+///
+/// Much cheaper than formatting with [`crate::log!`],
+/// useful for quick numeric tracing in hot loops.
+///
+/// Args can be any [`Log64`].
+///
+/// # Example
+///
+/// ```
+/// use jiminy_log::log_64;
+///
+/// let (i, amt) = (3usize, -1i64);
+/// log_64!(i, amt, true);
+/// ```
+///
+/// will print
+///
+/// ```md
+/// Program log: 0x3, 0xffffffffffffffff, 0x1, 0x0, 0x0
+/// ```
+#[macro_export]
+macro_rules! log_64 {
+    ($($val:expr),+ $(,)?) => {
+        $crate::sol_log_u64s([$($crate::Log64::to_log_64($val)),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn signed_sign_extends(i8_: i8, i16_: i16, i32_: i32, i64_: i64) {
+            prop_assert_eq!(i8_.to_log_64(), i8_ as i64 as u64);
+            prop_assert_eq!(i16_.to_log_64(), i16_ as i64 as u64);
+            prop_assert_eq!(i32_.to_log_64(), i32_ as i64 as u64);
+            prop_assert_eq!(i64_.to_log_64(), i64_ as u64);
+        }
+    }
+
+    #[test]
+    fn log_64_compiles() {
+        log_64!(1u8);
+        log_64!(1u8, -2i16, 3u32, -4i64, true,);
+        sol_log_u64s([]);
+        sol_log_i64s([-1, 2]);
+    }
+}
@@ -0,0 +1,232 @@
+//! Slice-based memory ops that use the `sol_memcpy_`, `sol_memmove_`, `sol_memcmp_`
+//! and `sol_memset_` syscalls on-chain for large lengths, and `core` otherwise.
+//!
+//! ## Choosing between the syscall and `core`
+//!
+//! Each syscall costs a flat [`MEM_OP_BASE_CU`] for lengths up to
+//! `MEM_OP_BASE_CU * MEM_OP_BYTES_PER_CU` bytes, see [`syscall_cus`],
+//! plus a few CUs of argument setup.
+//!
+//! `core`'s impls compile to load/store loops, which cost CUs linear in length,
+//! but can be unrolled and avoid the syscall's fixed cost when the length is small and known at compile-time.
+//!
+//! All functions here use `core` for lengths below [`SYSCALL_MIN_LEN`] and the syscall otherwise.
+//! All functions are `#[inline(always)]` so that the branch is optimized away for
+//! compile-time lengths.
+//!
+//! Off-chain, the syscalls are replaced with equivalent native impls.
+
+#![cfg_attr(not(test), no_std)]
+#![allow(unexpected_cfgs)]
+
+use core::{cmp::Ordering, ops::Range};
+
+pub mod sys;
+
+/// Minimum cost of each mem op syscall
+pub const MEM_OP_BASE_CU: u64 = 10;
+
+/// Each mem op syscall costs 1 CU per this many bytes, if that exceeds [`MEM_OP_BASE_CU`]
+pub const MEM_OP_BYTES_PER_CU: u64 = 250;
+
+/// Lengths at or above this use the syscalls on-chain, lengths below use `core`'s impls.
+///
+/// The per-op crossover lengths, from which the syscall costs no more CUs than `core`'s impl
+/// for runtime lengths, are measured by the `mem-bench` test program and recorded in its
+/// `crossovers` snapshot. Its `syscall_min_len_within_crossovers` test checks that this
+/// single threshold lies between the smallest and largest of them.
+///
+/// Compile-time lengths can shift the crossovers since `core`'s impls may be unrolled.
+/// Use [`sys`] directly if the difference matters.
+pub const SYSCALL_MIN_LEN: usize = 32;
+
+/// Returns the CUs a mem op syscall on `n` bytes costs
+#[inline]
+pub const fn syscall_cus(n: usize) -> u64 {
+    let per_byte = n as u64 / MEM_OP_BYTES_PER_CU;
+    if per_byte > MEM_OP_BASE_CU {
+        per_byte
+    } else {
+        MEM_OP_BASE_CU
+    }
+}
+
+/// Copies `src` into `dst`. Equivalent to [`<[u8]>::copy_from_slice`].
+///
+/// # Panics
+/// - if `dst.len() != src.len()`
+#[inline(always)]
+pub fn copy(dst: &mut [u8], src: &[u8]) {
+    assert_eq!(dst.len(), src.len(), "copy: length mismatch");
+    if src.len() < SYSCALL_MIN_LEN {
+        dst.copy_from_slice(src);
+    } else {
+        // safety: dst and src cannot overlap since dst is &mut
+        unsafe { sys::memcpy(dst.as_mut_ptr(), src.as_ptr(), src.len()) }
+    }
+}
+
+/// Copies `buf[src]` to `buf[dest..dest + src.len()]`. The ranges may overlap.
+/// Equivalent to [`<[u8]>::copy_within`].
+///
+/// # Panics
+/// - if `src` is out of bounds or `src.start > src.end`
+/// - if `dest + src.len()` is out of bounds
+#[inline(always)]
+pub fn move_within(buf: &mut [u8], src: Range<usize>, dest: usize) {
+    let Range { start, end } = src;
+    assert!(start <= end, "move_within: src start > end");
+    assert!(end <= buf.len(), "move_within: src out of bounds");
+    let n = end - start;
+    assert!(dest <= buf.len() - n, "move_within: dest out of bounds");
+    if n < SYSCALL_MIN_LEN {
+        buf.copy_within(start..end, dest);
+    } else {
+        // safety: bounds checked above
+        unsafe {
+            let ptr = buf.as_mut_ptr();
+            sys::memmove(ptr.add(dest), ptr.add(start), n)
+        }
+    }
+}
+
+/// Lexicographically compares `a` and `b`. Equivalent to [`Ord::cmp`] for `[u8]`.
+#[inline(always)]
+pub fn compare(a: &[u8], b: &[u8]) -> Ordering {
+    let n = a.len().min(b.len());
+    if n < SYSCALL_MIN_LEN {
+        a.cmp(b)
+    } else {
+        // safety: both a and b are at least n long
+        let res = unsafe { sys::memcmp(a.as_ptr(), b.as_ptr(), n) };
+        res.cmp(&0).then(a.len().cmp(&b.len()))
+    }
+}
+
+/// Returns `a == b`
+#[inline(always)]
+pub fn eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    if a.len() < SYSCALL_MIN_LEN {
+        a == b
+    } else {
+        // safety: both a and b are a.len() long
+        unsafe { sys::memcmp(a.as_ptr(), b.as_ptr(), a.len()) == 0 }
+    }
+}
+
+/// Sets all bytes of `dst` to `val`. Equivalent to [`<[u8]>::fill`].
+#[inline(always)]
+pub fn fill(dst: &mut [u8], val: u8) {
+    if dst.len() < SYSCALL_MIN_LEN {
+        dst.fill(val);
+    } else {
+        unsafe { sys::memset(dst.as_mut_ptr(), val, dst.len()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    /// lengths that straddle [`SYSCALL_MIN_LEN`]
+    fn any_bytes() -> impl Strategy<Value = Vec<u8>> {
+        vec(any::<u8>(), 0..=SYSCALL_MIN_LEN * 3)
+    }
+
+    /// pairs of bytes that share a random-length prefix,
+    /// so that compare() doesn't only test the first byte
+    fn any_similar_pair() -> impl Strategy<Value = (Vec<u8>, Vec<u8>)> {
+        (any_bytes(), any_bytes(), any::<prop::sample::Index>()).prop_map(|(a, b, i)| {
+            let n = a.len().min(b.len());
+            let prefix = i.index(n + 1);
+            let mut b = b;
+            b[..prefix].copy_from_slice(&a[..prefix]);
+            (a, b)
+        })
+    }
+
+    #[test]
+    fn syscall_cus_matches_runtime() {
+        assert_eq!(syscall_cus(0), 10);
+        assert_eq!(syscall_cus(2749), 10);
+        assert_eq!(syscall_cus(2750), 11);
+        assert_eq!(syscall_cus(10 * 1024 * 1024), 41943);
+    }
+
+    proptest! {
+        #[test]
+        fn copy_eq_core(src in any_bytes(), init: u8) {
+            let mut dst = vec![init; src.len()];
+            copy(&mut dst, &src);
+            prop_assert_eq!(dst, src);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn move_within_eq_core(
+            buf in any_bytes(),
+            [a, b, c]: [prop::sample::Index; 3],
+        ) {
+            let len = buf.len();
+            let (start, end) = {
+                let (x, y) = (a.index(len + 1), b.index(len + 1));
+                (x.min(y), x.max(y))
+            };
+            let dest = c.index(len - (end - start) + 1);
+
+            let mut expected = buf.clone();
+            expected.copy_within(start..end, dest);
+            let mut actual = buf;
+            move_within(&mut actual, start..end, dest);
+            prop_assert_eq!(actual, expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn compare_eq_core((a, b) in any_similar_pair()) {
+            prop_assert_eq!(compare(&a, &b), a.cmp(&b));
+            prop_assert_eq!(compare(&b, &a), b.cmp(&a));
+            prop_assert_eq!(compare(&a, &a), Ordering::Equal);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn eq_eq_core((a, b) in any_similar_pair()) {
+            prop_assert_eq!(eq(&a, &b), a == b);
+            prop_assert!(eq(&a, &a));
+            prop_assert!(eq(&b, &b.clone()));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn fill_eq_core(mut buf in any_bytes(), val: u8) {
+            fill(&mut buf, val);
+            prop_assert!(buf.iter().all(|b| *b == val));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_len_mismatch_panics() {
+        copy(&mut [0; SYSCALL_MIN_LEN], &[0; SYSCALL_MIN_LEN + 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn move_within_oob_panics() {
+        move_within(
+            &mut [0; SYSCALL_MIN_LEN * 2],
+            0..SYSCALL_MIN_LEN,
+            SYSCALL_MIN_LEN + 1,
+        );
+    }
+}
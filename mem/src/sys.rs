@@ -0,0 +1,76 @@
+//! Thin wrappers over the syscalls, with native impls off-chain
+//! that have the same semantics as agave's syscall impls.
+//!
+//! All fns are unsafe with the same safety requirements as their libc counterparts.
+
+/// # Safety
+///
+/// Same as libc's `memcpy`
+#[inline(always)]
+pub unsafe fn memcpy(dst: *mut u8, src: *const u8, n: usize) {
+    #[cfg(target_os = "solana")]
+    {
+        jiminy_syscall::sol_memcpy_(dst, src, n as u64);
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        core::ptr::copy_nonoverlapping(src, dst, n);
+    }
+}
+
+/// # Safety
+///
+/// Same as libc's `memmove`
+#[inline(always)]
+pub unsafe fn memmove(dst: *mut u8, src: *const u8, n: usize) {
+    #[cfg(target_os = "solana")]
+    {
+        jiminy_syscall::sol_memmove_(dst, src, n as u64);
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        core::ptr::copy(src, dst, n);
+    }
+}
+
+/// Returns the difference of the first differing bytes `s1[i] - s2[i]`, or 0 if all equal
+///
+/// # Safety
+///
+/// Same as libc's `memcmp`
+#[inline(always)]
+pub unsafe fn memcmp(s1: *const u8, s2: *const u8, n: usize) -> i32 {
+    #[cfg(target_os = "solana")]
+    {
+        let mut res = 0i32;
+        jiminy_syscall::sol_memcmp_(s1, s2, n as u64, &mut res);
+        res
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        let [s1, s2] = [s1, s2].map(|s| core::slice::from_raw_parts(s, n));
+        s1.iter()
+            .zip(s2)
+            .find(|(a, b)| a != b)
+            .map_or(0, |(a, b)| *a as i32 - *b as i32)
+    }
+}
+
+/// # Safety
+///
+/// Same as libc's `memset`
+#[inline(always)]
+pub unsafe fn memset(s: *mut u8, c: u8, n: usize) {
+    #[cfg(target_os = "solana")]
+    {
+        jiminy_syscall::sol_memset_(s, c, n as u64);
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        core::ptr::write_bytes(s, c, n);
+    }
+}
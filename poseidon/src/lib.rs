@@ -0,0 +1,362 @@
+//! Poseidon hashing via the `sol_poseidon` syscall.
+//!
+//! Inputs are BN254 scalar field elements, each 32 bytes, in the selected [`Endianness`].
+//! The result is in the same endianness. This is the circom-compatible parameter set
+//! used by light-protocol's compressed state Merkle trees.
+//!
+//! Off-chain, this falls back to [`solana_poseidon`], the same impl used by the runtime,
+//! if the `solana-poseidon` feature is enabled.
+
+#![cfg_attr(not(test), no_std)]
+#![allow(unexpected_cfgs)]
+
+use core::{fmt::Display, mem::MaybeUninit};
+
+pub const POSEIDON_HASH_LEN: usize = 32;
+
+pub const FIELD_ELEM_LEN: usize = 32;
+
+/// Maximum number of inputs the runtime accepts for a single hash.
+///
+/// Exceeding this aborts the program, so [`poseidon`] checks this before making the syscall.
+pub const MAX_INPUTS: usize = 12;
+
+pub type PoseidonHash = [u8; POSEIDON_HASH_LEN];
+
+pub type FieldElem = [u8; FIELD_ELEM_LEN];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u64)]
+pub enum PoseidonParameters {
+    /// BN254 scalar field, x^5 S-box, circom-compatible constants.
+    /// Supports 1 to [`MAX_INPUTS`] inputs.
+    #[default]
+    Bn254X5 = 0,
+}
+
+impl PoseidonParameters {
+    #[inline(always)]
+    pub const fn into_u64(self) -> u64 {
+        self as u64
+    }
+}
+
+/// Endianness of both the inputs and the result
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u64)]
+pub enum Endianness {
+    #[default]
+    BigEndian = 0,
+    LittleEndian = 1,
+}
+
+impl Endianness {
+    #[inline(always)]
+    pub const fn into_u64(self) -> u64 {
+        self as u64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PoseidonError {
+    InvalidParameters,
+    InvalidEndianness,
+    /// More than [`MAX_INPUTS`] inputs
+    InvalidNumberOfInputs,
+    EmptyInput,
+    InvalidInputLength,
+    BytesToPrimeFieldElement,
+    /// An input is not less than the BN254 scalar field modulus
+    InputLargerThanModulus,
+    VecToArray,
+    U64Tou8,
+    BytesToBigInt,
+    InvalidWidthCircom,
+    Unexpected,
+}
+
+impl PoseidonError {
+    /// Returns the syscall's return code for this error
+    #[inline(always)]
+    pub const fn into_u64(self) -> u64 {
+        match self {
+            Self::InvalidParameters => 1,
+            Self::InvalidEndianness => 2,
+            Self::InvalidNumberOfInputs => 3,
+            Self::EmptyInput => 4,
+            Self::InvalidInputLength => 5,
+            Self::BytesToPrimeFieldElement => 6,
+            Self::InputLargerThanModulus => 7,
+            Self::VecToArray => 8,
+            Self::U64Tou8 => 9,
+            Self::BytesToBigInt => 10,
+            Self::InvalidWidthCircom => 11,
+            Self::Unexpected => 12,
+        }
+    }
+
+    /// Returns `None` if `code` is not one of the syscall's error return codes
+    #[inline(always)]
+    pub const fn try_from_u64(code: u64) -> Option<Self> {
+        Some(match code {
+            1 => Self::InvalidParameters,
+            2 => Self::InvalidEndianness,
+            3 => Self::InvalidNumberOfInputs,
+            4 => Self::EmptyInput,
+            5 => Self::InvalidInputLength,
+            6 => Self::BytesToPrimeFieldElement,
+            7 => Self::InputLargerThanModulus,
+            8 => Self::VecToArray,
+            9 => Self::U64Tou8,
+            10 => Self::BytesToBigInt,
+            11 => Self::InvalidWidthCircom,
+            12 => Self::Unexpected,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for PoseidonError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::InvalidParameters => "InvalidParameters",
+            Self::InvalidEndianness => "InvalidEndianness",
+            Self::InvalidNumberOfInputs => "InvalidNumberOfInputs",
+            Self::EmptyInput => "EmptyInput",
+            Self::InvalidInputLength => "InvalidInputLength",
+            Self::BytesToPrimeFieldElement => "BytesToPrimeFieldElement",
+            Self::InputLargerThanModulus => "InputLargerThanModulus",
+            Self::VecToArray => "VecToArray",
+            Self::U64Tou8 => "U64Tou8",
+            Self::BytesToBigInt => "BytesToBigInt",
+            Self::InvalidWidthCircom => "InvalidWidthCircom",
+            Self::Unexpected => "Unexpected",
+        })
+    }
+}
+
+impl core::error::Error for PoseidonError {}
+
+impl From<PoseidonError> for u64 {
+    #[inline(always)]
+    fn from(value: PoseidonError) -> Self {
+        value.into_u64()
+    }
+}
+
+/// Poseidon hash of `inputs`.
+///
+/// # Example
+///
+/// ```no_run
+/// use jiminy_poseidon::{poseidon, Endianness, PoseidonParameters};
+///
+/// let hash = poseidon(
+///     PoseidonParameters::Bn254X5,
+///     Endianness::BigEndian,
+///     &[[1u8; 32], [2u8; 32]],
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     hash,
+///     [
+///         13, 84, 225, 147, 143, 138, 140, 28, 125, 235, 94, 3, 85, 242, 99, 25, 32, 123,
+///         132, 254, 156, 162, 206, 27, 38, 231, 53, 200, 41, 130, 25, 144
+///     ]
+/// );
+/// ```
+#[inline]
+pub fn poseidon(
+    parameters: PoseidonParameters,
+    endianness: Endianness,
+    inputs: &[FieldElem],
+) -> Result<PoseidonHash, PoseidonError> {
+    let mut hash = MaybeUninit::uninit();
+    poseidon_to(parameters, endianness, inputs, &mut hash)?;
+    Ok(unsafe { hash.assume_init() })
+}
+
+/// This is potentially more compute-efficient than [`poseidon`] by using out-pointers.
+///
+/// The compiler has proven to be unable to optimize away the move/copy in
+/// `MaybeUninit::assume_init()` in many cases, especially when the returned value is
+/// only dropped at entrypoint exit.
+///
+/// A memory leak can potentially occur if the initialized value in the MaybeUninits
+/// are not dropped, but [u8; 32] is Copy so its fine
+#[inline]
+pub fn poseidon_to<'dst>(
+    parameters: PoseidonParameters,
+    endianness: Endianness,
+    inputs: &[FieldElem],
+    hash: &'dst mut MaybeUninit<PoseidonHash>,
+) -> Result<&'dst mut PoseidonHash, PoseidonError> {
+    if inputs.len() > MAX_INPUTS {
+        return Err(PoseidonError::InvalidNumberOfInputs);
+    }
+    // syscall expects an array of slices
+    let mut vals: [&[u8]; MAX_INPUTS] = [&[]; MAX_INPUTS];
+    vals.iter_mut()
+        .zip(inputs)
+        .for_each(|(v, input)| *v = input.as_slice());
+    let vals = &vals[..inputs.len()];
+
+    #[cfg(target_os = "solana")]
+    {
+        let res = unsafe {
+            jiminy_syscall::sol_poseidon(
+                parameters.into_u64(),
+                endianness.into_u64(),
+                vals.as_ptr().cast(),
+                vals.len() as u64,
+                hash.as_mut_ptr().cast(),
+            )
+        };
+        match res {
+            0 => Ok(unsafe { hash.assume_init_mut() }),
+            code => Err(match PoseidonError::try_from_u64(code) {
+                Some(e) => e,
+                None => PoseidonError::Unexpected,
+            }),
+        }
+    }
+
+    #[cfg(all(not(target_os = "solana"), any(test, feature = "solana-poseidon")))]
+    {
+        let PoseidonParameters::Bn254X5 = parameters;
+        let res = solana_poseidon::hashv(
+            solana_poseidon::Parameters::Bn254X5,
+            match endianness {
+                Endianness::BigEndian => solana_poseidon::Endianness::BigEndian,
+                Endianness::LittleEndian => solana_poseidon::Endianness::LittleEndian,
+            },
+            vals,
+        )
+        .map_err(|e| match PoseidonError::try_from_u64(e.into()) {
+            Some(e) => e,
+            None => PoseidonError::Unexpected,
+        })?;
+        Ok(hash.write(res.to_bytes()))
+    }
+
+    #[cfg(all(not(target_os = "solana"), not(any(test, feature = "solana-poseidon"))))]
+    {
+        core::hint::black_box((parameters, endianness, vals, hash));
+        unreachable!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    const ALL_ERRS: [PoseidonError; 12] = [
+        PoseidonError::InvalidParameters,
+        PoseidonError::InvalidEndianness,
+        PoseidonError::InvalidNumberOfInputs,
+        PoseidonError::EmptyInput,
+        PoseidonError::InvalidInputLength,
+        PoseidonError::BytesToPrimeFieldElement,
+        PoseidonError::InputLargerThanModulus,
+        PoseidonError::VecToArray,
+        PoseidonError::U64Tou8,
+        PoseidonError::BytesToBigInt,
+        PoseidonError::InvalidWidthCircom,
+        PoseidonError::Unexpected,
+    ];
+
+    #[test]
+    fn error_code_round_trip() {
+        for e in ALL_ERRS {
+            assert_eq!(PoseidonError::try_from_u64(e.into_u64()), Some(e));
+        }
+        for code in [0, 13, u64::MAX] {
+            assert_eq!(PoseidonError::try_from_u64(code), None);
+        }
+    }
+
+    #[test]
+    fn error_codes_eq_solana() {
+        for e in ALL_ERRS {
+            let sol = solana_poseidon::PoseidonSyscallError::from(e.into_u64());
+            assert_eq!(u64::from(sol), e.into_u64());
+        }
+    }
+
+    #[test]
+    fn known_vectors() {
+        let p = PoseidonParameters::Bn254X5;
+        assert_eq!(
+            poseidon(p, Endianness::BigEndian, &[[1; 32]]).unwrap(),
+            [
+                5, 191, 172, 229, 129, 238, 97, 119, 204, 25, 198, 197, 99, 99, 166, 136, 130, 241,
+                30, 132, 7, 172, 99, 157, 185, 145, 224, 210, 127, 27, 117, 230
+            ]
+        );
+        assert_eq!(
+            poseidon(p, Endianness::LittleEndian, &[[1; 32], [2; 32]]).unwrap(),
+            [
+                144, 25, 130, 41, 200, 53, 231, 38, 27, 206, 162, 156, 254, 132, 123, 32, 25, 99,
+                242, 85, 3, 94, 235, 125, 28, 140, 138, 143, 147, 225, 84, 13
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_inputs_err() {
+        let p = PoseidonParameters::Bn254X5;
+        assert_eq!(
+            poseidon(p, Endianness::BigEndian, &[[0; 32]; MAX_INPUTS + 1]),
+            Err(PoseidonError::InvalidNumberOfInputs)
+        );
+        assert!(poseidon(p, Endianness::BigEndian, &[[0; 32]; MAX_INPUTS]).is_ok());
+        assert!(poseidon(p, Endianness::BigEndian, &[]).is_err());
+        assert_eq!(
+            poseidon(p, Endianness::BigEndian, &[[0xff; 32]]),
+            Err(PoseidonError::InputLargerThanModulus)
+        );
+    }
+
+    fn any_field_elem() -> impl Strategy<Value = FieldElem> {
+        // top byte < 0x30 guarantees < modulus
+        (0u8..0x30, any::<[u8; 31]>()).prop_map(|(top, rest)| {
+            let mut res = [top; 32];
+            res[1..].copy_from_slice(&rest);
+            res
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn le_eq_reversed_be(inputs in vec(any_field_elem(), 1..=MAX_INPUTS)) {
+            let p = PoseidonParameters::Bn254X5;
+            let be = poseidon(p, Endianness::BigEndian, &inputs).unwrap();
+            let le_inputs: Vec<_> = inputs
+                .iter()
+                .map(|i| {
+                    let mut i = *i;
+                    i.reverse();
+                    i
+                })
+                .collect();
+            let mut le = poseidon(p, Endianness::LittleEndian, &le_inputs).unwrap();
+            le.reverse();
+            prop_assert_eq!(be, le);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_eq_non_to(inputs in vec(any_field_elem(), 0..=MAX_INPUTS + 1)) {
+            let p = PoseidonParameters::Bn254X5;
+            let mut hash = MaybeUninit::uninit();
+            prop_assert_eq!(
+                poseidon_to(p, Endianness::BigEndian, &inputs, &mut hash).copied(),
+                poseidon(p, Endianness::BigEndian, &inputs)
+            );
+        }
+    }
+}
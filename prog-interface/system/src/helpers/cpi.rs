@@ -0,0 +1,205 @@
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    pda::PdaSigner,
+    program_error::{BuiltInProgramError, ProgramError},
+    Cpi,
+};
+use jiminy_sysvar_rent::Rent;
+
+use crate::{
+    AllocateIxData, AssignIxData, CreateAccountIxData, NewAllocateIxAccsBuilder,
+    NewAssignIxAccsBuilder, NewCreateAccountIxAccsBuilder, NewTransferIxAccsBuilder,
+    TransferIxData, ID,
+};
+
+/// System program `CreateAccount` CPI, funding `new` with the rent-exempt minimum of `space`
+/// from the [`Rent`] sysvar.
+///
+/// `signers` should contain the [`PdaSigner`]s of `funding` and `new` if they are PDAs.
+///
+/// Fails if `new` already has lamports. Use [`create_pda_account_idempotent`] to handle that case.
+#[allow(clippy::too_many_arguments)]
+#[inline]
+pub fn create_account<'account, const MAX_CPI_ACCOUNTS: usize>(
+    cpi: &mut Cpi<MAX_CPI_ACCOUNTS>,
+    abr: &mut Abr,
+    sys_prog: AccountHandle<'account>,
+    funding: AccountHandle<'account>,
+    new: AccountHandle<'account>,
+    space: usize,
+    owner: &[u8; 32],
+    signers: &[PdaSigner],
+) -> Result<(), ProgramError> {
+    let lamports = Rent::sysvar_get()?.min_balance(space);
+    create_account_with_lamports(
+        cpi, abr, sys_prog, funding, new, lamports, space, owner, signers,
+    )
+}
+
+/// [`create_account`] with an explicit `lamports`, e.g. to fund `new`
+/// beyond the rent-exempt minimum to prepay for future reallocs,
+/// or to avoid the [`Rent`] sysvar syscall if it has already been fetched.
+#[allow(clippy::too_many_arguments)]
+#[inline]
+pub fn create_account_with_lamports<'account, const MAX_CPI_ACCOUNTS: usize>(
+    cpi: &mut Cpi<MAX_CPI_ACCOUNTS>,
+    abr: &mut Abr,
+    sys_prog: AccountHandle<'account>,
+    funding: AccountHandle<'account>,
+    new: AccountHandle<'account>,
+    lamports: u64,
+    space: usize,
+    owner: &[u8; 32],
+    signers: &[PdaSigner],
+) -> Result<(), ProgramError> {
+    cpi.invoke_signed_handle(
+        abr,
+        sys_prog,
+        CreateAccountIxData::new(lamports, space, owner).as_buf(),
+        NewCreateAccountIxAccsBuilder::start()
+            .with_funding(funding)
+            .with_new(new)
+            .build()
+            .into_account_handle_perms(),
+        signers,
+    )
+}
+
+/// System program `Transfer` CPI.
+///
+/// `signers` should contain the [`PdaSigner`] of `from` if it is a PDA.
+#[inline]
+pub fn transfer<'account, const MAX_CPI_ACCOUNTS: usize>(
+    cpi: &mut Cpi<MAX_CPI_ACCOUNTS>,
+    abr: &mut Abr,
+    sys_prog: AccountHandle<'account>,
+    from: AccountHandle<'account>,
+    to: AccountHandle<'account>,
+    lamports: u64,
+    signers: &[PdaSigner],
+) -> Result<(), ProgramError> {
+    cpi.invoke_signed_handle(
+        abr,
+        sys_prog,
+        TransferIxData::new(lamports).as_buf(),
+        NewTransferIxAccsBuilder::start()
+            .with_from(from)
+            .with_to(to)
+            .build()
+            .into_account_handle_perms(),
+        signers,
+    )
+}
+
+/// System program `Allocate` CPI.
+///
+/// `signers` should contain the [`PdaSigner`] of `account` if it is a PDA.
+#[inline]
+pub fn allocate<'account, const MAX_CPI_ACCOUNTS: usize>(
+    cpi: &mut Cpi<MAX_CPI_ACCOUNTS>,
+    abr: &mut Abr,
+    sys_prog: AccountHandle<'account>,
+    account: AccountHandle<'account>,
+    space: usize,
+    signers: &[PdaSigner],
+) -> Result<(), ProgramError> {
+    cpi.invoke_signed_handle(
+        abr,
+        sys_prog,
+        AllocateIxData::new(space).as_buf(),
+        NewAllocateIxAccsBuilder::start()
+            .with_allocate(account)
+            .build()
+            .into_account_handle_perms(),
+        signers,
+    )
+}
+
+/// System program `Assign` CPI.
+///
+/// `signers` should contain the [`PdaSigner`] of `account` if it is a PDA.
+#[inline]
+pub fn assign<'account, const MAX_CPI_ACCOUNTS: usize>(
+    cpi: &mut Cpi<MAX_CPI_ACCOUNTS>,
+    abr: &mut Abr,
+    sys_prog: AccountHandle<'account>,
+    account: AccountHandle<'account>,
+    owner: &[u8; 32],
+    signers: &[PdaSigner],
+) -> Result<(), ProgramError> {
+    cpi.invoke_signed_handle(
+        abr,
+        sys_prog,
+        AssignIxData::new(owner).as_buf(),
+        NewAssignIxAccsBuilder::start()
+            .with_assign(account)
+            .build()
+            .into_account_handle_perms(),
+        signers,
+    )
+}
+
+/// Creates a rent-exempt account of `space` bytes owned by `owner` at `new`,
+/// which would usually be a PDA whose [`PdaSigner`] is in `signers`.
+///
+/// - If `new` has no lamports, this is a single [`create_account_with_lamports`] CPI.
+/// - Else, `new` was funded in advance, which would make [`create_account`] fail.
+///     - If `new` is already owned by `owner` with data length `space`,
+///       any shortfall from the rent-exempt minimum is [`transfer`]red from `funding`.
+///     - Else if `new` is owned by the system program with no data,
+///       the shortfall is [`transfer`]red, then `new` is [`allocate`]d and [`assign`]ed.
+///     - Else `new` is some other existing account, e.g. one owned by `owner` with a
+///       different data length, and this errs with
+///       [`BuiltInProgramError::AccountAlreadyInitialized`] before making any CPI.
+#[allow(clippy::too_many_arguments)]
+#[inline]
+pub fn create_pda_account_idempotent<'account, const MAX_CPI_ACCOUNTS: usize>(
+    cpi: &mut Cpi<MAX_CPI_ACCOUNTS>,
+    abr: &mut Abr,
+    sys_prog: AccountHandle<'account>,
+    funding: AccountHandle<'account>,
+    new: AccountHandle<'account>,
+    space: usize,
+    owner: &[u8; 32],
+    rent: &Rent,
+    signers: &[PdaSigner],
+) -> Result<(), ProgramError> {
+    let min_balance = rent.min_balance(space);
+    let acc = abr.get(new);
+    let lamports = acc.lamports();
+
+    if lamports == 0 {
+        return create_account_with_lamports(
+            cpi,
+            abr,
+            sys_prog,
+            funding,
+            new,
+            min_balance,
+            space,
+            owner,
+            signers,
+        );
+    }
+
+    let is_created = acc.owner() == owner && acc.data_len() == space;
+    // allocate would fail for anything other than an empty system account
+    if !is_created && (*acc.owner() != ID || acc.data_len() != 0) {
+        return Err(ProgramError::from_builtin(
+            BuiltInProgramError::AccountAlreadyInitialized,
+        ));
+    }
+    match min_balance.checked_sub(lamports) {
+        None | Some(0) => (),
+        Some(shortfall) => transfer(cpi, abr, sys_prog, funding, new, shortfall, signers)?,
+    }
+    if is_created {
+        return Ok(());
+    }
+    allocate(cpi, abr, sys_prog, new, space, signers)?;
+    // allocate succeeding means new is owned by the system program
+    if *owner != ID {
+        assign(cpi, abr, sys_prog, new, owner, signers)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,8 @@
+//! Higher-level helpers that combine system program CPIs
+//! with direct account manipulation
+
+mod cpi;
+mod realloc;
+
+pub use cpi::*;
+pub use realloc::*;
@@ -0,0 +1,78 @@
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::ProgramError,
+    Cpi,
+};
+use jiminy_sysvar_rent::Rent;
+
+use crate::{NewTransferIxAccsBuilder, TransferIxData, ID, TRANSFER_IX_ACCS_LEN};
+
+/// Extension trait adding rent-aware reallocs to [`Abr`].
+pub trait ReallocRentExempt {
+    /// Reallocs `handle`'s account data to `new_len` while keeping the account rent-exempt.
+    ///
+    /// - If growing and the account's balance falls short of the new rent-exempt minimum,
+    ///   the shortfall is transferred from `payer`:
+    ///     - directly if `payer` is owned by the same program as `handle` i.e. the currently executing program
+    ///     - else via a system program transfer CPI. `payer` must be a signer of the transaction
+    ///       and the system program must be in the transaction in this case.
+    /// - If shrinking, any lamports in excess of the new rent-exempt minimum are refunded directly to `refund_to`,
+    ///   which may be the same account as `payer`.
+    ///
+    /// `handle` must be owned by the currently executing program,
+    /// else [`jiminy_cpi::account::Account::realloc`] would not be allowed by the runtime anyway.
+    fn realloc_rent_exempt(
+        &mut self,
+        handle: AccountHandle<'_>,
+        new_len: usize,
+        payer: AccountHandle<'_>,
+        refund_to: AccountHandle<'_>,
+        rent: &Rent,
+    ) -> Result<(), ProgramError>;
+}
+
+impl ReallocRentExempt for Abr {
+    #[inline]
+    fn realloc_rent_exempt(
+        &mut self,
+        handle: AccountHandle<'_>,
+        new_len: usize,
+        payer: AccountHandle<'_>,
+        refund_to: AccountHandle<'_>,
+        rent: &Rent,
+    ) -> Result<(), ProgramError> {
+        let min_balance = rent.min_balance(new_len);
+        let acc = self.get(handle);
+        let lamports = acc.lamports();
+
+        if new_len < acc.data_len() {
+            self.get_mut(handle).realloc(new_len)?;
+            match lamports.checked_sub(min_balance) {
+                None | Some(0) => Ok(()),
+                Some(excess) => self.transfer_direct(handle, refund_to, excess),
+            }
+        } else {
+            match min_balance.checked_sub(lamports) {
+                None | Some(0) => (),
+                Some(shortfall) => {
+                    if self.get(payer).owner() == acc.owner() {
+                        self.transfer_direct(payer, handle, shortfall)?;
+                    } else {
+                        Cpi::<TRANSFER_IX_ACCS_LEN>::new().invoke_signed(
+                            self,
+                            &ID,
+                            TransferIxData::new(shortfall).as_buf(),
+                            NewTransferIxAccsBuilder::start()
+                                .with_from(payer)
+                                .with_to(handle)
+                                .build()
+                                .into_account_handle_perms(),
+                            &[],
+                        )?;
+                    }
+                }
+            }
+            self.get_mut(handle).realloc(new_len)
+        }
+    }
+}
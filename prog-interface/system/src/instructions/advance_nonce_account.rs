@@ -0,0 +1,74 @@
+use generic_array_struct::generic_array_struct;
+use jiminy_cpi::{account::AccountHandle, AccountPerms};
+
+use super::{internal_utils::signer_writable_to_perms, AccountHandlePerms};
+
+pub const ADVANCE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [4, 0, 0, 0];
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct AdvanceNonceAccountIxAccs<T> {
+    pub nonce: T,
+    pub recent_blockhashes: T,
+    pub authority: T,
+}
+
+impl<T: Copy> AdvanceNonceAccountIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; ADVANCE_NONCE_ACCOUNT_IX_ACCS_LEN])
+    }
+}
+
+pub type AdvanceNonceAccountIxAccounts<'a> = AdvanceNonceAccountIxAccs<AccountHandle<'a>>;
+pub type AdvanceNonceAccountIxAccsFlag = AdvanceNonceAccountIxAccs<bool>;
+pub type AdvanceNonceAccountIxAccountPerms = AdvanceNonceAccountIxAccs<AccountPerms>;
+
+pub const ADVANCE_NONCE_ACCOUNT_IX_IS_SIGNER: AdvanceNonceAccountIxAccsFlag =
+    AdvanceNonceAccountIxAccs::memset(false).const_with_authority(true);
+
+pub const ADVANCE_NONCE_ACCOUNT_IX_IS_WRITABLE: AdvanceNonceAccountIxAccsFlag =
+    AdvanceNonceAccountIxAccs::memset(false).const_with_nonce(true);
+
+pub const ADVANCE_NONCE_ACCOUNT_IX_ACCOUNT_PERMS: AdvanceNonceAccountIxAccountPerms =
+    AdvanceNonceAccountIxAccs(signer_writable_to_perms(
+        ADVANCE_NONCE_ACCOUNT_IX_IS_SIGNER.0,
+        ADVANCE_NONCE_ACCOUNT_IX_IS_WRITABLE.0,
+    ));
+
+pub const ADVANCE_NONCE_ACCOUNT_IX_DATA_LEN: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct AdvanceNonceAccountIxData([u8; ADVANCE_NONCE_ACCOUNT_IX_DATA_LEN]);
+
+impl AdvanceNonceAccountIxData {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(ADVANCE_NONCE_ACCOUNT_IX_DISCM)
+    }
+
+    #[inline]
+    pub fn as_buf(&self) -> &[u8; ADVANCE_NONCE_ACCOUNT_IX_DATA_LEN] {
+        &self.0
+    }
+}
+
+impl Default for AdvanceNonceAccountIxData {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'accounts> AdvanceNonceAccountIxAccounts<'accounts> {
+    #[inline]
+    pub fn into_account_handle_perms(
+        self,
+    ) -> AccountHandlePerms<'accounts, ADVANCE_NONCE_ACCOUNT_IX_ACCS_LEN> {
+        self.0
+            .into_iter()
+            .zip(ADVANCE_NONCE_ACCOUNT_IX_ACCOUNT_PERMS.0)
+    }
+}
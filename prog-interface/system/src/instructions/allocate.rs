@@ -0,0 +1,61 @@
+use generic_array_struct::generic_array_struct;
+use jiminy_cpi::{account::AccountHandle, AccountPerms};
+
+use super::{internal_utils::signer_writable_to_perms, AccountHandlePerms};
+
+pub const ALLOCATE_IX_DISCM: [u8; 4] = [8, 0, 0, 0];
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct AllocateIxAccs<T> {
+    pub allocate: T,
+}
+
+impl<T> AllocateIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; ALLOCATE_IX_ACCS_LEN])
+    }
+}
+
+pub type AllocateIxAccounts<'a> = AllocateIxAccs<AccountHandle<'a>>;
+pub type AllocateIxAccsFlag = AllocateIxAccs<bool>;
+pub type AllocateIxAccountPerms = AllocateIxAccs<AccountPerms>;
+
+pub const ALLOCATE_IX_IS_SIGNER: AllocateIxAccsFlag = AllocateIxAccs::memset(true);
+
+pub const ALLOCATE_IX_IS_WRITABLE: AllocateIxAccsFlag = AllocateIxAccs::memset(true);
+
+pub const ALLOCATE_IX_ACCOUNT_PERMS: AllocateIxAccountPerms = AllocateIxAccs(
+    signer_writable_to_perms(ALLOCATE_IX_IS_SIGNER.0, ALLOCATE_IX_IS_WRITABLE.0),
+);
+
+pub const ALLOCATE_IX_DATA_LEN: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct AllocateIxData([u8; ALLOCATE_IX_DATA_LEN]);
+
+impl AllocateIxData {
+    #[inline]
+    pub fn new(space: usize) -> Self {
+        let mut ix_data = [0u8; ALLOCATE_IX_DATA_LEN];
+        ix_data[0..4].copy_from_slice(&ALLOCATE_IX_DISCM);
+        ix_data[4..].copy_from_slice(&(space as u64).to_le_bytes());
+
+        Self(ix_data)
+    }
+
+    #[inline]
+    pub fn as_buf(&self) -> &[u8; ALLOCATE_IX_DATA_LEN] {
+        &self.0
+    }
+}
+
+impl<'accounts> AllocateIxAccounts<'accounts> {
+    #[inline]
+    pub fn into_account_handle_perms(self) -> AccountHandlePerms<'accounts, ALLOCATE_IX_ACCS_LEN> {
+        self.0.into_iter().zip(ALLOCATE_IX_ACCOUNT_PERMS.0)
+    }
+}
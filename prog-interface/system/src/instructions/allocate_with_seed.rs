@@ -0,0 +1,87 @@
+use generic_array_struct::generic_array_struct;
+use jiminy_cpi::{account::AccountHandle, program_error::ProgramError, AccountPerms};
+use jiminy_pda::MAX_SEED_LEN;
+
+use super::{
+    internal_utils::{signer_writable_to_perms, write_seed},
+    AccountHandlePerms,
+};
+
+pub const ALLOCATE_WITH_SEED_IX_DISCM: [u8; 4] = [9, 0, 0, 0];
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct AllocateWithSeedIxAccs<T> {
+    pub allocate: T,
+    pub base: T,
+}
+
+impl<T: Copy> AllocateWithSeedIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; ALLOCATE_WITH_SEED_IX_ACCS_LEN])
+    }
+}
+
+pub type AllocateWithSeedIxAccounts<'a> = AllocateWithSeedIxAccs<AccountHandle<'a>>;
+pub type AllocateWithSeedIxAccsFlag = AllocateWithSeedIxAccs<bool>;
+pub type AllocateWithSeedIxAccountPerms = AllocateWithSeedIxAccs<AccountPerms>;
+
+pub const ALLOCATE_WITH_SEED_IX_IS_SIGNER: AllocateWithSeedIxAccsFlag =
+    AllocateWithSeedIxAccs::memset(false).const_with_base(true);
+
+pub const ALLOCATE_WITH_SEED_IX_IS_WRITABLE: AllocateWithSeedIxAccsFlag =
+    AllocateWithSeedIxAccs::memset(false).const_with_allocate(true);
+
+pub const ALLOCATE_WITH_SEED_IX_ACCOUNT_PERMS: AllocateWithSeedIxAccountPerms =
+    AllocateWithSeedIxAccs(signer_writable_to_perms(
+        ALLOCATE_WITH_SEED_IX_IS_SIGNER.0,
+        ALLOCATE_WITH_SEED_IX_IS_WRITABLE.0,
+    ));
+
+/// Length of ix data with a seed of length [`MAX_SEED_LEN`]
+pub const ALLOCATE_WITH_SEED_IX_DATA_MAX_LEN: usize = 84 + MAX_SEED_LEN;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AllocateWithSeedIxData {
+    buf: [u8; ALLOCATE_WITH_SEED_IX_DATA_MAX_LEN],
+    len: usize,
+}
+
+impl AllocateWithSeedIxData {
+    /// Errs with [`jiminy_cpi::program_error::BuiltInProgramError::MaxSeedLengthExceeded`]
+    /// if `seed` is longer than [`MAX_SEED_LEN`]
+    #[inline]
+    pub fn new(
+        base: &[u8; 32],
+        seed: &str,
+        space: usize,
+        owner: &[u8; 32],
+    ) -> Result<Self, ProgramError> {
+        let mut buf = [0u8; ALLOCATE_WITH_SEED_IX_DATA_MAX_LEN];
+        buf[0..4].copy_from_slice(&ALLOCATE_WITH_SEED_IX_DISCM);
+        buf[4..36].copy_from_slice(base);
+        let i = 36 + write_seed(&mut buf[36..], seed)?;
+        buf[i..i + 8].copy_from_slice(&(space as u64).to_le_bytes());
+        buf[i + 8..i + 40].copy_from_slice(owner);
+
+        Ok(Self { buf, len: i + 40 })
+    }
+
+    #[inline]
+    pub fn as_buf(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl<'accounts> AllocateWithSeedIxAccounts<'accounts> {
+    #[inline]
+    pub fn into_account_handle_perms(
+        self,
+    ) -> AccountHandlePerms<'accounts, ALLOCATE_WITH_SEED_IX_ACCS_LEN> {
+        self.0
+            .into_iter()
+            .zip(ALLOCATE_WITH_SEED_IX_ACCOUNT_PERMS.0)
+    }
+}
@@ -0,0 +1,79 @@
+use generic_array_struct::generic_array_struct;
+use jiminy_cpi::{account::AccountHandle, program_error::ProgramError, AccountPerms};
+use jiminy_pda::MAX_SEED_LEN;
+
+use super::{
+    internal_utils::{signer_writable_to_perms, write_seed},
+    AccountHandlePerms,
+};
+
+pub const ASSIGN_WITH_SEED_IX_DISCM: [u8; 4] = [10, 0, 0, 0];
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct AssignWithSeedIxAccs<T> {
+    pub assign: T,
+    pub base: T,
+}
+
+impl<T: Copy> AssignWithSeedIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; ASSIGN_WITH_SEED_IX_ACCS_LEN])
+    }
+}
+
+pub type AssignWithSeedIxAccounts<'a> = AssignWithSeedIxAccs<AccountHandle<'a>>;
+pub type AssignWithSeedIxAccsFlag = AssignWithSeedIxAccs<bool>;
+pub type AssignWithSeedIxAccountPerms = AssignWithSeedIxAccs<AccountPerms>;
+
+pub const ASSIGN_WITH_SEED_IX_IS_SIGNER: AssignWithSeedIxAccsFlag =
+    AssignWithSeedIxAccs::memset(false).const_with_base(true);
+
+pub const ASSIGN_WITH_SEED_IX_IS_WRITABLE: AssignWithSeedIxAccsFlag =
+    AssignWithSeedIxAccs::memset(false).const_with_assign(true);
+
+pub const ASSIGN_WITH_SEED_IX_ACCOUNT_PERMS: AssignWithSeedIxAccountPerms =
+    AssignWithSeedIxAccs(signer_writable_to_perms(
+        ASSIGN_WITH_SEED_IX_IS_SIGNER.0,
+        ASSIGN_WITH_SEED_IX_IS_WRITABLE.0,
+    ));
+
+/// Length of ix data with a seed of length [`MAX_SEED_LEN`]
+pub const ASSIGN_WITH_SEED_IX_DATA_MAX_LEN: usize = 76 + MAX_SEED_LEN;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AssignWithSeedIxData {
+    buf: [u8; ASSIGN_WITH_SEED_IX_DATA_MAX_LEN],
+    len: usize,
+}
+
+impl AssignWithSeedIxData {
+    /// Errs with [`jiminy_cpi::program_error::BuiltInProgramError::MaxSeedLengthExceeded`]
+    /// if `seed` is longer than [`MAX_SEED_LEN`]
+    #[inline]
+    pub fn new(base: &[u8; 32], seed: &str, owner: &[u8; 32]) -> Result<Self, ProgramError> {
+        let mut buf = [0u8; ASSIGN_WITH_SEED_IX_DATA_MAX_LEN];
+        buf[0..4].copy_from_slice(&ASSIGN_WITH_SEED_IX_DISCM);
+        buf[4..36].copy_from_slice(base);
+        let i = 36 + write_seed(&mut buf[36..], seed)?;
+        buf[i..i + 32].copy_from_slice(owner);
+
+        Ok(Self { buf, len: i + 32 })
+    }
+
+    #[inline]
+    pub fn as_buf(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl<'accounts> AssignWithSeedIxAccounts<'accounts> {
+    #[inline]
+    pub fn into_account_handle_perms(
+        self,
+    ) -> AccountHandlePerms<'accounts, ASSIGN_WITH_SEED_IX_ACCS_LEN> {
+        self.0.into_iter().zip(ASSIGN_WITH_SEED_IX_ACCOUNT_PERMS.0)
+    }
+}
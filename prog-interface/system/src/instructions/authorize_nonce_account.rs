@@ -0,0 +1,70 @@
+use generic_array_struct::generic_array_struct;
+use jiminy_cpi::{account::AccountHandle, AccountPerms};
+
+use super::{internal_utils::signer_writable_to_perms, AccountHandlePerms};
+
+pub const AUTHORIZE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [7, 0, 0, 0];
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct AuthorizeNonceAccountIxAccs<T> {
+    pub nonce: T,
+    pub authority: T,
+}
+
+impl<T: Copy> AuthorizeNonceAccountIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; AUTHORIZE_NONCE_ACCOUNT_IX_ACCS_LEN])
+    }
+}
+
+pub type AuthorizeNonceAccountIxAccounts<'a> = AuthorizeNonceAccountIxAccs<AccountHandle<'a>>;
+pub type AuthorizeNonceAccountIxAccsFlag = AuthorizeNonceAccountIxAccs<bool>;
+pub type AuthorizeNonceAccountIxAccountPerms = AuthorizeNonceAccountIxAccs<AccountPerms>;
+
+pub const AUTHORIZE_NONCE_ACCOUNT_IX_IS_SIGNER: AuthorizeNonceAccountIxAccsFlag =
+    AuthorizeNonceAccountIxAccs::memset(false).const_with_authority(true);
+
+pub const AUTHORIZE_NONCE_ACCOUNT_IX_IS_WRITABLE: AuthorizeNonceAccountIxAccsFlag =
+    AuthorizeNonceAccountIxAccs::memset(false).const_with_nonce(true);
+
+pub const AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNT_PERMS: AuthorizeNonceAccountIxAccountPerms =
+    AuthorizeNonceAccountIxAccs(signer_writable_to_perms(
+        AUTHORIZE_NONCE_ACCOUNT_IX_IS_SIGNER.0,
+        AUTHORIZE_NONCE_ACCOUNT_IX_IS_WRITABLE.0,
+    ));
+
+pub const AUTHORIZE_NONCE_ACCOUNT_IX_DATA_LEN: usize = 36;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct AuthorizeNonceAccountIxData([u8; AUTHORIZE_NONCE_ACCOUNT_IX_DATA_LEN]);
+
+impl AuthorizeNonceAccountIxData {
+    #[inline]
+    pub fn new(new_authority: &[u8; 32]) -> Self {
+        let mut ix_data = [0u8; AUTHORIZE_NONCE_ACCOUNT_IX_DATA_LEN];
+        ix_data[0..4].copy_from_slice(&AUTHORIZE_NONCE_ACCOUNT_IX_DISCM);
+        ix_data[4..].copy_from_slice(new_authority);
+
+        Self(ix_data)
+    }
+
+    #[inline]
+    pub fn as_buf(&self) -> &[u8; AUTHORIZE_NONCE_ACCOUNT_IX_DATA_LEN] {
+        &self.0
+    }
+}
+
+impl<'accounts> AuthorizeNonceAccountIxAccounts<'accounts> {
+    #[inline]
+    pub fn into_account_handle_perms(
+        self,
+    ) -> AccountHandlePerms<'accounts, AUTHORIZE_NONCE_ACCOUNT_IX_ACCS_LEN> {
+        self.0
+            .into_iter()
+            .zip(AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNT_PERMS.0)
+    }
+}
@@ -0,0 +1,91 @@
+use generic_array_struct::generic_array_struct;
+use jiminy_cpi::{account::AccountHandle, program_error::ProgramError, AccountPerms};
+use jiminy_pda::MAX_SEED_LEN;
+
+use super::{
+    internal_utils::{signer_writable_to_perms, write_seed},
+    AccountHandlePerms,
+};
+
+pub const CREATE_ACCOUNT_WITH_SEED_IX_DISCM: [u8; 4] = [3, 0, 0, 0];
+
+/// `base` may be the same account as `funding`
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct CreateAccountWithSeedIxAccs<T> {
+    pub funding: T,
+    pub new: T,
+    pub base: T,
+}
+
+impl<T: Copy> CreateAccountWithSeedIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; CREATE_ACCOUNT_WITH_SEED_IX_ACCS_LEN])
+    }
+}
+
+pub type CreateAccountWithSeedIxAccounts<'a> = CreateAccountWithSeedIxAccs<AccountHandle<'a>>;
+pub type CreateAccountWithSeedIxAccsFlag = CreateAccountWithSeedIxAccs<bool>;
+pub type CreateAccountWithSeedIxAccountPerms = CreateAccountWithSeedIxAccs<AccountPerms>;
+
+pub const CREATE_ACCOUNT_WITH_SEED_IX_IS_SIGNER: CreateAccountWithSeedIxAccsFlag =
+    CreateAccountWithSeedIxAccs::memset(true).const_with_new(false);
+
+pub const CREATE_ACCOUNT_WITH_SEED_IX_IS_WRITABLE: CreateAccountWithSeedIxAccsFlag =
+    CreateAccountWithSeedIxAccs::memset(true).const_with_base(false);
+
+pub const CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNT_PERMS: CreateAccountWithSeedIxAccountPerms =
+    CreateAccountWithSeedIxAccs(signer_writable_to_perms(
+        CREATE_ACCOUNT_WITH_SEED_IX_IS_SIGNER.0,
+        CREATE_ACCOUNT_WITH_SEED_IX_IS_WRITABLE.0,
+    ));
+
+/// Length of ix data with a seed of length [`MAX_SEED_LEN`]
+pub const CREATE_ACCOUNT_WITH_SEED_IX_DATA_MAX_LEN: usize = 92 + MAX_SEED_LEN;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CreateAccountWithSeedIxData {
+    buf: [u8; CREATE_ACCOUNT_WITH_SEED_IX_DATA_MAX_LEN],
+    len: usize,
+}
+
+impl CreateAccountWithSeedIxData {
+    /// Errs with [`jiminy_cpi::program_error::BuiltInProgramError::MaxSeedLengthExceeded`]
+    /// if `seed` is longer than [`MAX_SEED_LEN`]
+    #[inline]
+    pub fn new(
+        base: &[u8; 32],
+        seed: &str,
+        lamports: u64,
+        space: usize,
+        owner: &[u8; 32],
+    ) -> Result<Self, ProgramError> {
+        let mut buf = [0u8; CREATE_ACCOUNT_WITH_SEED_IX_DATA_MAX_LEN];
+        buf[0..4].copy_from_slice(&CREATE_ACCOUNT_WITH_SEED_IX_DISCM);
+        buf[4..36].copy_from_slice(base);
+        let i = 36 + write_seed(&mut buf[36..], seed)?;
+        buf[i..i + 8].copy_from_slice(&lamports.to_le_bytes());
+        buf[i + 8..i + 16].copy_from_slice(&(space as u64).to_le_bytes());
+        buf[i + 16..i + 48].copy_from_slice(owner);
+
+        Ok(Self { buf, len: i + 48 })
+    }
+
+    #[inline]
+    pub fn as_buf(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl<'accounts> CreateAccountWithSeedIxAccounts<'accounts> {
+    #[inline]
+    pub fn into_account_handle_perms(
+        self,
+    ) -> AccountHandlePerms<'accounts, CREATE_ACCOUNT_WITH_SEED_IX_ACCS_LEN> {
+        self.0
+            .into_iter()
+            .zip(CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNT_PERMS.0)
+    }
+}
@@ -0,0 +1,75 @@
+use generic_array_struct::generic_array_struct;
+use jiminy_cpi::{account::AccountHandle, AccountPerms};
+
+use super::{internal_utils::signer_writable_to_perms, AccountHandlePerms};
+
+pub const INITIALIZE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [6, 0, 0, 0];
+
+/// Account data length of a nonce account
+pub const NONCE_ACCOUNT_LEN: usize = 80;
+
+/// No signers required, so nonce accounts can be PDAs
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct InitializeNonceAccountIxAccs<T> {
+    pub nonce: T,
+    pub recent_blockhashes: T,
+    pub rent: T,
+}
+
+impl<T: Copy> InitializeNonceAccountIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; INITIALIZE_NONCE_ACCOUNT_IX_ACCS_LEN])
+    }
+}
+
+pub type InitializeNonceAccountIxAccounts<'a> = InitializeNonceAccountIxAccs<AccountHandle<'a>>;
+pub type InitializeNonceAccountIxAccsFlag = InitializeNonceAccountIxAccs<bool>;
+pub type InitializeNonceAccountIxAccountPerms = InitializeNonceAccountIxAccs<AccountPerms>;
+
+pub const INITIALIZE_NONCE_ACCOUNT_IX_IS_SIGNER: InitializeNonceAccountIxAccsFlag =
+    InitializeNonceAccountIxAccs::memset(false);
+
+pub const INITIALIZE_NONCE_ACCOUNT_IX_IS_WRITABLE: InitializeNonceAccountIxAccsFlag =
+    InitializeNonceAccountIxAccs::memset(false).const_with_nonce(true);
+
+pub const INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNT_PERMS: InitializeNonceAccountIxAccountPerms =
+    InitializeNonceAccountIxAccs(signer_writable_to_perms(
+        INITIALIZE_NONCE_ACCOUNT_IX_IS_SIGNER.0,
+        INITIALIZE_NONCE_ACCOUNT_IX_IS_WRITABLE.0,
+    ));
+
+pub const INITIALIZE_NONCE_ACCOUNT_IX_DATA_LEN: usize = 36;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct InitializeNonceAccountIxData([u8; INITIALIZE_NONCE_ACCOUNT_IX_DATA_LEN]);
+
+impl InitializeNonceAccountIxData {
+    #[inline]
+    pub fn new(authority: &[u8; 32]) -> Self {
+        let mut ix_data = [0u8; INITIALIZE_NONCE_ACCOUNT_IX_DATA_LEN];
+        ix_data[0..4].copy_from_slice(&INITIALIZE_NONCE_ACCOUNT_IX_DISCM);
+        ix_data[4..].copy_from_slice(authority);
+
+        Self(ix_data)
+    }
+
+    #[inline]
+    pub fn as_buf(&self) -> &[u8; INITIALIZE_NONCE_ACCOUNT_IX_DATA_LEN] {
+        &self.0
+    }
+}
+
+impl<'accounts> InitializeNonceAccountIxAccounts<'accounts> {
+    #[inline]
+    pub fn into_account_handle_perms(
+        self,
+    ) -> AccountHandlePerms<'accounts, INITIALIZE_NONCE_ACCOUNT_IX_ACCS_LEN> {
+        self.0
+            .into_iter()
+            .zip(INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNT_PERMS.0)
+    }
+}
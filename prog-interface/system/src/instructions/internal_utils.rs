@@ -1,4 +1,8 @@
-use jiminy_cpi::AccountPerms;
+use jiminy_cpi::{
+    program_error::{BuiltInProgramError, ProgramError},
+    AccountPerms,
+};
+use jiminy_pda::MAX_SEED_LEN;
 
 pub(crate) const fn signer_writable_to_perms<const N: usize>(
     is_signer: [bool; N],
@@ -18,3 +22,19 @@ pub(crate) const fn signer_writable_to_perms<const N: usize>(
     }
     res
 }
+
+/// Writes the bincode serialization of a `String` seed,
+/// a u64 LE length prefix followed by its bytes, to the start of `dst`.
+///
+/// Returns the number of bytes written.
+pub(crate) fn write_seed(dst: &mut [u8], seed: &str) -> Result<usize, ProgramError> {
+    if seed.len() > MAX_SEED_LEN {
+        return Err(ProgramError::from_builtin(
+            BuiltInProgramError::MaxSeedLengthExceeded,
+        ));
+    }
+    let end = 8 + seed.len();
+    dst[..8].copy_from_slice(&(seed.len() as u64).to_le_bytes());
+    dst[8..end].copy_from_slice(seed.as_bytes());
+    Ok(end)
+}
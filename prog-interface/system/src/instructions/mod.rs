@@ -1,21 +1,173 @@
-//! TODO: other instructions
-//!
 //! TODO: the `generic_array_struct` structs should be part of core crate
 //! portable across different environments (client-side, wasm etc) instead
 
 use core::{array, iter::Zip};
 use jiminy_cpi::{account::AccountHandle, AccountPerms};
 
+mod advance_nonce_account;
+mod allocate;
+mod allocate_with_seed;
 mod assign;
+mod assign_with_seed;
+mod authorize_nonce_account;
 mod create_account;
+mod create_account_with_seed;
+mod initialize_nonce_account;
 mod internal_utils;
 mod transfer;
+mod transfer_with_seed;
+mod upgrade_nonce_account;
+mod withdraw_nonce_account;
 
+pub use advance_nonce_account::*;
+pub use allocate::*;
+pub use allocate_with_seed::*;
 pub use assign::*;
+pub use assign_with_seed::*;
+pub use authorize_nonce_account::*;
 pub use create_account::*;
+pub use create_account_with_seed::*;
+pub use initialize_nonce_account::*;
 pub use transfer::*;
+pub use transfer_with_seed::*;
+pub use upgrade_nonce_account::*;
+pub use withdraw_nonce_account::*;
 
 pub type AccountHandlePerms<'account, const ACCOUNTS: usize> = Zip<
     array::IntoIter<AccountHandle<'account>, ACCOUNTS>,
     array::IntoIter<AccountPerms, ACCOUNTS>,
 >;
+
+#[cfg(test)]
+mod tests {
+    use jiminy_cpi::program_error::{BuiltInProgramError, ProgramError};
+    use jiminy_pda::MAX_SEED_LEN;
+    use proptest::prelude::*;
+    use solana_instruction::Instruction;
+    use solana_pubkey::Pubkey;
+    use solana_system_interface::instruction as sys_ix;
+
+    use crate::ID;
+
+    use super::*;
+
+    fn assert_matches<const N: usize>(
+        ix: &Instruction,
+        data: &[u8],
+        perms: [AccountPerms; N],
+    ) -> Result<(), TestCaseError> {
+        prop_assert_eq!(ix.data.as_slice(), data);
+        prop_assert_eq!(
+            ix.accounts
+                .iter()
+                .map(|m| (m.is_signer, m.is_writable))
+                .collect::<Vec<_>>(),
+            perms
+                .iter()
+                .map(|p| (p.is_signer, p.is_writable))
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    fn seed() -> impl Strategy<Value = String> {
+        proptest::string::string_regex(&format!("[a-zA-Z0-9_-]{{0,{MAX_SEED_LEN}}}")).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn seeded_ixs_match_sys_interface(
+            [a, b, c, d]: [[u8; 32]; 4],
+            seed in seed(),
+            lamports: u64,
+            space in 0..=u32::MAX as usize,
+        ) {
+            let [a_pk, b_pk, c_pk, d_pk] = [a, b, c, d].map(Pubkey::new_from_array);
+
+            assert_matches(
+                &sys_ix::create_account_with_seed(
+                    &a_pk, &b_pk, &c_pk, &seed, lamports, space as u64, &d_pk,
+                ),
+                CreateAccountWithSeedIxData::new(&c, &seed, lamports, space, &d)?.as_buf(),
+                CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNT_PERMS.0,
+            )?;
+            assert_matches(
+                &sys_ix::allocate_with_seed(&a_pk, &b_pk, &seed, space as u64, &c_pk),
+                AllocateWithSeedIxData::new(&b, &seed, space, &c)?.as_buf(),
+                ALLOCATE_WITH_SEED_IX_ACCOUNT_PERMS.0,
+            )?;
+            assert_matches(
+                &sys_ix::assign_with_seed(&a_pk, &b_pk, &seed, &c_pk),
+                AssignWithSeedIxData::new(&b, &seed, &c)?.as_buf(),
+                ASSIGN_WITH_SEED_IX_ACCOUNT_PERMS.0,
+            )?;
+            assert_matches(
+                &sys_ix::transfer_with_seed(&a_pk, &b_pk, seed.clone(), &c_pk, &d_pk, lamports),
+                TransferWithSeedIxData::new(lamports, &seed, &c)?.as_buf(),
+                TRANSFER_WITH_SEED_IX_ACCOUNT_PERMS.0,
+            )?;
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn unseeded_ixs_match_sys_interface(
+            [a, b, c]: [[u8; 32]; 3],
+            lamports: u64,
+            space in 0..=u32::MAX as usize,
+        ) {
+            let [a_pk, b_pk, c_pk] = [a, b, c].map(Pubkey::new_from_array);
+
+            assert_matches(
+                &sys_ix::allocate(&a_pk, space as u64),
+                AllocateIxData::new(space).as_buf(),
+                ALLOCATE_IX_ACCOUNT_PERMS.0,
+            )?;
+            assert_matches(
+                &sys_ix::advance_nonce_account(&a_pk, &b_pk),
+                AdvanceNonceAccountIxData::new().as_buf(),
+                ADVANCE_NONCE_ACCOUNT_IX_ACCOUNT_PERMS.0,
+            )?;
+            assert_matches(
+                &sys_ix::withdraw_nonce_account(&a_pk, &b_pk, &c_pk, lamports),
+                WithdrawNonceAccountIxData::new(lamports).as_buf(),
+                WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNT_PERMS.0,
+            )?;
+            assert_matches(
+                &sys_ix::authorize_nonce_account(&a_pk, &b_pk, &c_pk),
+                AuthorizeNonceAccountIxData::new(&c).as_buf(),
+                AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNT_PERMS.0,
+            )?;
+            assert_matches(
+                &sys_ix::upgrade_nonce_account(a_pk),
+                UpgradeNonceAccountIxData::new().as_buf(),
+                UPGRADE_NONCE_ACCOUNT_IX_ACCOUNT_PERMS.0,
+            )?;
+
+            let [create, init] = <[_; 2]>::try_from(
+                sys_ix::create_nonce_account(&a_pk, &b_pk, &c_pk, lamports),
+            )
+            .unwrap();
+            let create_data = CreateAccountIxData::new(lamports, NONCE_ACCOUNT_LEN, &ID);
+            prop_assert_eq!(create.data.as_slice(), create_data.as_buf());
+            assert_matches(
+                &init,
+                InitializeNonceAccountIxData::new(&c).as_buf(),
+                INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNT_PERMS.0,
+            )?;
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn seed_too_long_fails(
+            seed in proptest::string::string_regex(&format!("[a-z]{{{},64}}", MAX_SEED_LEN + 1)).unwrap(),
+        ) {
+            let e = ProgramError::from_builtin(BuiltInProgramError::MaxSeedLengthExceeded);
+            prop_assert_eq!(CreateAccountWithSeedIxData::new(&[0; 32], &seed, 0, 0, &[0; 32]).unwrap_err(), e);
+            prop_assert_eq!(AllocateWithSeedIxData::new(&[0; 32], &seed, 0, &[0; 32]).unwrap_err(), e);
+            prop_assert_eq!(AssignWithSeedIxData::new(&[0; 32], &seed, &[0; 32]).unwrap_err(), e);
+            prop_assert_eq!(TransferWithSeedIxData::new(0, &seed, &[0; 32]).unwrap_err(), e);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use generic_array_struct::generic_array_struct;
+use jiminy_cpi::{account::AccountHandle, program_error::ProgramError, AccountPerms};
+use jiminy_pda::MAX_SEED_LEN;
+
+use super::{
+    internal_utils::{signer_writable_to_perms, write_seed},
+    AccountHandlePerms,
+};
+
+pub const TRANSFER_WITH_SEED_IX_DISCM: [u8; 4] = [11, 0, 0, 0];
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct TransferWithSeedIxAccs<T> {
+    pub from: T,
+    pub base: T,
+    pub to: T,
+}
+
+impl<T: Copy> TransferWithSeedIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; TRANSFER_WITH_SEED_IX_ACCS_LEN])
+    }
+}
+
+pub type TransferWithSeedIxAccounts<'a> = TransferWithSeedIxAccs<AccountHandle<'a>>;
+pub type TransferWithSeedIxAccsFlag = TransferWithSeedIxAccs<bool>;
+pub type TransferWithSeedIxAccountPerms = TransferWithSeedIxAccs<AccountPerms>;
+
+pub const TRANSFER_WITH_SEED_IX_IS_SIGNER: TransferWithSeedIxAccsFlag =
+    TransferWithSeedIxAccs::memset(false).const_with_base(true);
+
+pub const TRANSFER_WITH_SEED_IX_IS_WRITABLE: TransferWithSeedIxAccsFlag =
+    TransferWithSeedIxAccs::memset(true).const_with_base(false);
+
+pub const TRANSFER_WITH_SEED_IX_ACCOUNT_PERMS: TransferWithSeedIxAccountPerms =
+    TransferWithSeedIxAccs(signer_writable_to_perms(
+        TRANSFER_WITH_SEED_IX_IS_SIGNER.0,
+        TRANSFER_WITH_SEED_IX_IS_WRITABLE.0,
+    ));
+
+/// Length of ix data with a seed of length [`MAX_SEED_LEN`]
+pub const TRANSFER_WITH_SEED_IX_DATA_MAX_LEN: usize = 52 + MAX_SEED_LEN;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TransferWithSeedIxData {
+    buf: [u8; TRANSFER_WITH_SEED_IX_DATA_MAX_LEN],
+    len: usize,
+}
+
+impl TransferWithSeedIxData {
+    /// `from_seed` and `from_owner` are used with the `base` account
+    /// to derive the `from` account's address.
+    ///
+    /// Errs with [`jiminy_cpi::program_error::BuiltInProgramError::MaxSeedLengthExceeded`]
+    /// if `from_seed` is longer than [`MAX_SEED_LEN`]
+    #[inline]
+    pub fn new(
+        lamports: u64,
+        from_seed: &str,
+        from_owner: &[u8; 32],
+    ) -> Result<Self, ProgramError> {
+        let mut buf = [0u8; TRANSFER_WITH_SEED_IX_DATA_MAX_LEN];
+        buf[0..4].copy_from_slice(&TRANSFER_WITH_SEED_IX_DISCM);
+        buf[4..12].copy_from_slice(&lamports.to_le_bytes());
+        let i = 12 + write_seed(&mut buf[12..], from_seed)?;
+        buf[i..i + 32].copy_from_slice(from_owner);
+
+        Ok(Self { buf, len: i + 32 })
+    }
+
+    #[inline]
+    pub fn as_buf(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl<'accounts> TransferWithSeedIxAccounts<'accounts> {
+    #[inline]
+    pub fn into_account_handle_perms(
+        self,
+    ) -> AccountHandlePerms<'accounts, TRANSFER_WITH_SEED_IX_ACCS_LEN> {
+        self.0
+            .into_iter()
+            .zip(TRANSFER_WITH_SEED_IX_ACCOUNT_PERMS.0)
+    }
+}
@@ -0,0 +1,72 @@
+use generic_array_struct::generic_array_struct;
+use jiminy_cpi::{account::AccountHandle, AccountPerms};
+
+use super::{internal_utils::signer_writable_to_perms, AccountHandlePerms};
+
+pub const UPGRADE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [12, 0, 0, 0];
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct UpgradeNonceAccountIxAccs<T> {
+    pub nonce: T,
+}
+
+impl<T> UpgradeNonceAccountIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; UPGRADE_NONCE_ACCOUNT_IX_ACCS_LEN])
+    }
+}
+
+pub type UpgradeNonceAccountIxAccounts<'a> = UpgradeNonceAccountIxAccs<AccountHandle<'a>>;
+pub type UpgradeNonceAccountIxAccsFlag = UpgradeNonceAccountIxAccs<bool>;
+pub type UpgradeNonceAccountIxAccountPerms = UpgradeNonceAccountIxAccs<AccountPerms>;
+
+pub const UPGRADE_NONCE_ACCOUNT_IX_IS_SIGNER: UpgradeNonceAccountIxAccsFlag =
+    UpgradeNonceAccountIxAccs::memset(false);
+
+pub const UPGRADE_NONCE_ACCOUNT_IX_IS_WRITABLE: UpgradeNonceAccountIxAccsFlag =
+    UpgradeNonceAccountIxAccs::memset(true);
+
+pub const UPGRADE_NONCE_ACCOUNT_IX_ACCOUNT_PERMS: UpgradeNonceAccountIxAccountPerms =
+    UpgradeNonceAccountIxAccs(signer_writable_to_perms(
+        UPGRADE_NONCE_ACCOUNT_IX_IS_SIGNER.0,
+        UPGRADE_NONCE_ACCOUNT_IX_IS_WRITABLE.0,
+    ));
+
+pub const UPGRADE_NONCE_ACCOUNT_IX_DATA_LEN: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct UpgradeNonceAccountIxData([u8; UPGRADE_NONCE_ACCOUNT_IX_DATA_LEN]);
+
+impl UpgradeNonceAccountIxData {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(UPGRADE_NONCE_ACCOUNT_IX_DISCM)
+    }
+
+    #[inline]
+    pub fn as_buf(&self) -> &[u8; UPGRADE_NONCE_ACCOUNT_IX_DATA_LEN] {
+        &self.0
+    }
+}
+
+impl Default for UpgradeNonceAccountIxData {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'accounts> UpgradeNonceAccountIxAccounts<'accounts> {
+    #[inline]
+    pub fn into_account_handle_perms(
+        self,
+    ) -> AccountHandlePerms<'accounts, UPGRADE_NONCE_ACCOUNT_IX_ACCS_LEN> {
+        self.0
+            .into_iter()
+            .zip(UPGRADE_NONCE_ACCOUNT_IX_ACCOUNT_PERMS.0)
+    }
+}
@@ -0,0 +1,75 @@
+use generic_array_struct::generic_array_struct;
+use jiminy_cpi::{account::AccountHandle, AccountPerms};
+
+use super::{internal_utils::signer_writable_to_perms, AccountHandlePerms};
+
+pub const WITHDRAW_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [5, 0, 0, 0];
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct WithdrawNonceAccountIxAccs<T> {
+    pub nonce: T,
+    pub to: T,
+    pub recent_blockhashes: T,
+    pub rent: T,
+    pub authority: T,
+}
+
+impl<T: Copy> WithdrawNonceAccountIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; WITHDRAW_NONCE_ACCOUNT_IX_ACCS_LEN])
+    }
+}
+
+pub type WithdrawNonceAccountIxAccounts<'a> = WithdrawNonceAccountIxAccs<AccountHandle<'a>>;
+pub type WithdrawNonceAccountIxAccsFlag = WithdrawNonceAccountIxAccs<bool>;
+pub type WithdrawNonceAccountIxAccountPerms = WithdrawNonceAccountIxAccs<AccountPerms>;
+
+pub const WITHDRAW_NONCE_ACCOUNT_IX_IS_SIGNER: WithdrawNonceAccountIxAccsFlag =
+    WithdrawNonceAccountIxAccs::memset(false).const_with_authority(true);
+
+pub const WITHDRAW_NONCE_ACCOUNT_IX_IS_WRITABLE: WithdrawNonceAccountIxAccsFlag =
+    WithdrawNonceAccountIxAccs::memset(false)
+        .const_with_nonce(true)
+        .const_with_to(true);
+
+pub const WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNT_PERMS: WithdrawNonceAccountIxAccountPerms =
+    WithdrawNonceAccountIxAccs(signer_writable_to_perms(
+        WITHDRAW_NONCE_ACCOUNT_IX_IS_SIGNER.0,
+        WITHDRAW_NONCE_ACCOUNT_IX_IS_WRITABLE.0,
+    ));
+
+pub const WITHDRAW_NONCE_ACCOUNT_IX_DATA_LEN: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct WithdrawNonceAccountIxData([u8; WITHDRAW_NONCE_ACCOUNT_IX_DATA_LEN]);
+
+impl WithdrawNonceAccountIxData {
+    #[inline]
+    pub fn new(lamports: u64) -> Self {
+        let mut ix_data = [0u8; WITHDRAW_NONCE_ACCOUNT_IX_DATA_LEN];
+        ix_data[0..4].copy_from_slice(&WITHDRAW_NONCE_ACCOUNT_IX_DISCM);
+        ix_data[4..].copy_from_slice(&lamports.to_le_bytes());
+
+        Self(ix_data)
+    }
+
+    #[inline]
+    pub fn as_buf(&self) -> &[u8; WITHDRAW_NONCE_ACCOUNT_IX_DATA_LEN] {
+        &self.0
+    }
+}
+
+impl<'accounts> WithdrawNonceAccountIxAccounts<'accounts> {
+    #[inline]
+    pub fn into_account_handle_perms(
+        self,
+    ) -> AccountHandlePerms<'accounts, WITHDRAW_NONCE_ACCOUNT_IX_ACCS_LEN> {
+        self.0
+            .into_iter()
+            .zip(WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNT_PERMS.0)
+    }
+}
@@ -1,5 +1,11 @@
 #![cfg_attr(not(test), no_std)]
 
+mod helpers;
 mod instructions;
 
+pub use helpers::*;
 pub use instructions::*;
+
+pub const ID_STR: &str = "11111111111111111111111111111111";
+
+pub const ID: [u8; 32] = const_crypto::bs58::decode_pubkey(ID_STR);
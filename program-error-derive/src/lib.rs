@@ -0,0 +1,176 @@
+//! Derive macro for custom program error enums. Re-exported by `jiminy-program-error`
+//! with the `derive` feature; use it from there.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, LitStr, Path};
+
+/// Derives the following for a fieldless enum of custom program errors:
+///
+/// - `const fn as_u32(self) -> u32`: the custom error code, which is the variant's discriminant
+/// - `const fn try_from_u32(u32) -> Option<Self>`
+/// - `const fn name(&self) -> &'static str`: the variant's name
+/// - `fn log(self) -> Self`: logs the variant's name to an individual line and returns self
+/// - `From<Self> for ProgramError`
+/// - `TryFrom<ProgramError> for Self`, returning the original `ProgramError` on failure
+/// - `Display`, which writes the variant's name
+///
+/// Discriminants must fit in a u32. Use `#[repr(u32)]` for discriminants above `i32::MAX`.
+///
+/// If `jiminy_program_error` is not a direct dependency, e.g. when using
+/// `jiminy_entrypoint::program_error`, specify its path with
+/// `#[custom_error(crate = jiminy_entrypoint::program_error)]`.
+///
+/// # Example
+///
+/// ```
+/// use jiminy_program_error::{CustomError, ProgramError};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, CustomError)]
+/// pub enum MyError {
+///     NotEnoughFoo = 1,
+///     TooMuchBar,
+/// }
+///
+/// fn process() -> Result<(), ProgramError> {
+///     // logs "TooMuchBar"
+///     Err(MyError::TooMuchBar.log().into())
+/// }
+///
+/// let err = process().unwrap_err();
+/// assert_eq!(err, ProgramError::custom(2));
+/// assert_eq!(MyError::try_from(err), Ok(MyError::TooMuchBar));
+/// assert_eq!(MyError::TooMuchBar.to_string(), "TooMuchBar");
+/// ```
+#[proc_macro_derive(CustomError, attributes(custom_error))]
+pub fn derive_custom_error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let krate = crate_path(&input)?;
+    let DeriveInput {
+        ident,
+        generics,
+        data,
+        ..
+    } = input;
+
+    if !generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            generics,
+            "CustomError cannot be derived for generic enums",
+        ));
+    }
+    let Data::Enum(data) = data else {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "CustomError can only be derived for enums",
+        ));
+    };
+    if let Some(v) = data
+        .variants
+        .iter()
+        .find(|v| !matches!(v.fields, Fields::Unit))
+    {
+        return Err(syn::Error::new_spanned(
+            v,
+            "CustomError can only be derived for fieldless enums",
+        ));
+    }
+
+    let vars: Vec<_> = data.variants.iter().map(|v| &v.ident).collect();
+    let names: Vec<_> = vars
+        .iter()
+        .map(|v| LitStr::new(&v.to_string(), v.span()))
+        .collect();
+    let codes: Vec<_> = vars
+        .iter()
+        .map(|v| Ident::new(&format!("__CODE_{v}"), Span::call_site()))
+        .collect();
+
+    Ok(quote! {
+        impl #ident {
+            #[inline(always)]
+            pub const fn as_u32(self) -> u32 {
+                self as u32
+            }
+
+            #[inline]
+            pub const fn try_from_u32(code: u32) -> Option<Self> {
+                #(
+                    #[allow(non_upper_case_globals)]
+                    const #codes: u32 = #ident::#vars as u32;
+                )*
+                match code {
+                    #(#codes => Some(Self::#vars),)*
+                    _ => None,
+                }
+            }
+
+            #[inline]
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    #(Self::#vars => #names,)*
+                }
+            }
+
+            /// Logs this error's name to an individual line, then returns it
+            #[inline]
+            pub fn log(self) -> Self {
+                #krate::__private::sol_log(self.name());
+                self
+            }
+        }
+
+        impl ::core::convert::From<#ident> for #krate::ProgramError {
+            #[inline(always)]
+            fn from(value: #ident) -> Self {
+                #krate::ProgramError::custom(value.as_u32())
+            }
+        }
+
+        impl ::core::convert::TryFrom<#krate::ProgramError> for #ident {
+            type Error = #krate::ProgramError;
+
+            #[inline]
+            fn try_from(value: #krate::ProgramError) -> ::core::result::Result<Self, Self::Error> {
+                match value.try_into_custom() {
+                    Ok(code) => Self::try_from_u32(code).ok_or(value),
+                    Err(_) => Err(value),
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for #ident {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+    })
+}
+
+/// Parses `#[custom_error(crate = path)]`, defaulting to `::jiminy_program_error`
+fn crate_path(input: &DeriveInput) -> syn::Result<Path> {
+    let mut res = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("custom_error"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                res = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported custom_error attribute"))
+            }
+        })?;
+    }
+    Ok(res.unwrap_or_else(|| syn::parse_quote!(::jiminy_program_error)))
+}
@@ -0,0 +1,165 @@
+use crate::ProgramError;
+
+/// Extension trait for adding context to errors.
+///
+/// With the `debug-errors` feature, failures log `{file}:{line}: {label}`
+/// of the call site to an individual line.
+/// Without it, this compiles down to a bare `.map_err(Into::into)`.
+pub trait ResultExt<T> {
+    /// Converts the error into a [`ProgramError`],
+    /// logging `label` and the call site's location if `debug-errors` is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiminy_program_error::{BuiltInProgramError, ProgramError, ResultExt};
+    ///
+    /// fn check_len(data: &[u8]) -> Result<&[u8; 8], ProgramError> {
+    ///     data.try_into()
+    ///         .map_err(|_| BuiltInProgramError::InvalidInstructionData)
+    ///         .ctx("ix data len")
+    /// }
+    ///
+    /// assert!(check_len(&[0u8; 7]).is_err());
+    /// ```
+    fn ctx(self, label: &'static str) -> Result<T, ProgramError>;
+}
+
+impl<T, E: Into<ProgramError>> ResultExt<T> for Result<T, E> {
+    #[cfg_attr(feature = "debug-errors", track_caller)]
+    #[inline(always)]
+    fn ctx(self, label: &'static str) -> Result<T, ProgramError> {
+        match self {
+            Ok(t) => Ok(t),
+            Err(e) => {
+                #[cfg(feature = "debug-errors")]
+                {
+                    let loc = core::panic::Location::caller();
+                    Err(__ctx_err(e.into(), label, loc.file(), loc.line()))
+                }
+
+                #[cfg(not(feature = "debug-errors"))]
+                {
+                    let _ = label;
+                    Err(e.into())
+                }
+            }
+        }
+    }
+}
+
+/// Returns `Err(err.into())` from the current function if `cond` is false.
+///
+/// With the `debug-errors` feature, failures log `{file}:{line}: require!({cond})`
+/// of the call site to an individual line.
+/// Without it, this compiles down to a bare `if !cond { return Err(err.into()) }`.
+///
+/// # Example
+///
+/// ```
+/// use jiminy_program_error::{require, BuiltInProgramError, ProgramError};
+///
+/// fn check_signer(is_signer: bool) -> Result<(), ProgramError> {
+///     require!(is_signer, BuiltInProgramError::MissingRequiredSignature);
+///     Ok(())
+/// }
+///
+/// assert!(check_signer(false).is_err());
+/// ```
+#[macro_export]
+macro_rules! require {
+    ($cond:expr, $err:expr $(,)?) => {
+        if !$cond {
+            return Err($crate::__ctx_err(
+                ::core::convert::Into::into($err),
+                concat!("require!(", stringify!($cond), ")"),
+                file!(),
+                line!(),
+            )
+            .into());
+        }
+    };
+}
+
+/// Logs `{file}:{line}: {label}` if `debug-errors` is enabled, then returns `err`.
+///
+/// Used by [`ResultExt`] and [`require!`], not public API
+#[doc(hidden)]
+#[inline(always)]
+pub fn __ctx_err(err: ProgramError, label: &str, file: &str, line: u32) -> ProgramError {
+    #[cfg(feature = "debug-errors")]
+    log_ctx(label, file, line);
+
+    #[cfg(not(feature = "debug-errors"))]
+    let _ = (label, file, line);
+
+    err
+}
+
+#[cfg(feature = "debug-errors")]
+#[inline(never)]
+fn log_ctx(label: &str, file: &str, line: u32) {
+    fmt_ctx(label, file, line).log();
+}
+
+/// `{file}:{line}: {label}`, truncated at a char boundary to fit
+#[cfg(any(test, feature = "debug-errors"))]
+#[inline(always)]
+fn fmt_ctx(label: &str, file: &str, line: u32) -> jiminy_log::LogBuf {
+    use jiminy_log::LogArg;
+
+    let mut buf = jiminy_log::LogBuf::new();
+    buf.push_str(file);
+    buf.push_str(":");
+    line.write_to(&mut buf);
+    buf.push_str(": ");
+    buf.push_str(label);
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use jiminy_log::LOG_BUF_LEN;
+
+    use crate::{BuiltInProgramError, INVALID_ARGUMENT};
+
+    use super::*;
+
+    #[test]
+    fn fmt_ctx_basic() {
+        assert_eq!(
+            fmt_ctx("require!(x)", "src/lib.rs", 42).as_str(),
+            "src/lib.rs:42: require!(x)"
+        );
+    }
+
+    #[test]
+    fn fmt_ctx_long_label_truncated_at_char_boundary() {
+        // 3-byte chars, so the buffer limit falls mid-char
+        let label = "€".repeat(100);
+        let buf = fmt_ctx(&label, "a.rs", 1);
+        let s = buf.as_str();
+        assert!(format!("a.rs:1: {label}").starts_with(s));
+        assert!((LOG_BUF_LEN - 2..LOG_BUF_LEN).contains(&s.len()));
+    }
+
+    fn required(cond: bool) -> Result<(), ProgramError> {
+        require!(cond, BuiltInProgramError::InvalidArgument);
+        Ok(())
+    }
+
+    #[test]
+    fn require_returns_err_iff_false() {
+        assert_eq!(required(true), Ok(()));
+        assert_eq!(required(false), Err(ProgramError(INVALID_ARGUMENT)));
+    }
+
+    #[test]
+    fn ctx_preserves_err() {
+        let ok: Result<u8, BuiltInProgramError> = Ok(1);
+        assert_eq!(ok.ctx("ok"), Ok(1));
+
+        let err: Result<u8, BuiltInProgramError> = Err(BuiltInProgramError::InvalidArgument);
+        assert_eq!(err.ctx("err"), Err(ProgramError(INVALID_ARGUMENT)));
+    }
+}
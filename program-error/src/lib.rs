@@ -7,10 +7,39 @@
 //! - ProgramError maintains its `NonZeroU64` repr for efficiency; in bpf, returning from a cpi
 //!   can simply now just return the r0 register as is for success/error. This comes at the cost of
 //!   some debuggability since the errors are limited to their numeric codes. Pair this type with
-//!   something else if you need that debuggability.
+//!   something else if you need that debuggability, e.g. an enum that derives
+//!   `CustomError` with the `derive` feature.
+
+#![allow(unexpected_cfgs)]
 
 use core::{fmt::Display, num::NonZeroU64};
 
+mod ctx;
+
+#[cfg(any(test, feature = "solana-program-error"))]
+mod solana;
+
+pub use ctx::*;
+
+#[cfg(feature = "derive")]
+pub use jiminy_program_error_derive::CustomError;
+
+/// Used by code generated by `CustomError` and by `debug-errors` logging, not public API
+#[cfg(any(test, feature = "derive", feature = "debug-errors"))]
+#[doc(hidden)]
+pub mod __private {
+    #[inline]
+    pub fn sol_log(message: &str) {
+        #[cfg(target_os = "solana")]
+        unsafe {
+            jiminy_syscall::sol_log_(message.as_ptr(), message.len() as u64);
+        }
+
+        #[cfg(not(target_os = "solana"))]
+        core::hint::black_box(message);
+    }
+}
+
 /// pub field: Any non-zero u64 is a valid ProgramError
 /// put into r0 on bpf program exit
 ///
@@ -379,3 +408,57 @@ impl From<BuiltInProgramError> for u64 {
         value.into_u64()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use jiminy_program_error_derive::CustomError;
+
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, CustomError)]
+    #[custom_error(crate = crate)]
+    #[repr(u32)]
+    enum TestError {
+        Zero,
+        One,
+        Big = 0xFFFF_FFFF,
+        AfterGap = 10,
+        Next,
+    }
+
+    const ALL: [TestError; 5] = [
+        TestError::Zero,
+        TestError::One,
+        TestError::Big,
+        TestError::AfterGap,
+        TestError::Next,
+    ];
+
+    #[test]
+    fn custom_error_round_trip() {
+        let codes = [0, 1, u32::MAX, 10, 11];
+        let names = ["Zero", "One", "Big", "AfterGap", "Next"];
+        for ((e, code), name) in ALL.into_iter().zip(codes).zip(names) {
+            assert_eq!(e.as_u32(), code);
+            assert_eq!(TestError::try_from_u32(code), Some(e));
+            assert_eq!(e.name(), name);
+            assert_eq!(e.to_string(), name);
+            assert_eq!(e.log(), e);
+
+            let pe = ProgramError::from(e);
+            assert_eq!(pe, ProgramError::custom(code));
+            assert_eq!(TestError::try_from(pe), Ok(e));
+        }
+    }
+
+    #[test]
+    fn custom_error_invalid() {
+        for code in [2, 9, 12, u32::MAX - 1] {
+            assert_eq!(TestError::try_from_u32(code), None);
+            let pe = ProgramError::custom(code);
+            assert_eq!(TestError::try_from(pe), Err(pe));
+        }
+        let builtin = ProgramError::from_builtin(BuiltInProgramError::InvalidArgument);
+        assert_eq!(TestError::try_from(builtin), Err(builtin));
+    }
+}
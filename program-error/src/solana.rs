@@ -0,0 +1,184 @@
+//! Conversions to and from [`solana_program_error::ProgramError`]
+//! and [`solana_instruction_error::InstructionError`].
+//!
+//! Both use the same u64 repr as [`ProgramError`], so these simply go through u64.
+//! The solana types cannot represent non-builtin values with any of the upper 32 bits set,
+//! so conversions of such values to the solana types fail.
+
+use core::num::NonZeroU64;
+
+use solana_instruction_error::InstructionError;
+use solana_program_error::ProgramError as SolProgramError;
+
+use crate::{BuiltInProgramError, ProgramError, CUSTOM_ZERO};
+
+impl ProgramError {
+    /// Returns true if this is a builtin error or a custom error with none of the upper 32 bits set,
+    /// i.e. representable by [`SolProgramError`]
+    #[inline]
+    pub const fn is_canonical(self) -> bool {
+        match self.try_into_builtin() {
+            Ok(_) => true,
+            Err(custom) => self.0.get() == Self::custom(custom).0.get(),
+        }
+    }
+}
+
+impl From<SolProgramError> for ProgramError {
+    #[inline]
+    fn from(value: SolProgramError) -> Self {
+        // u64::from() is never 0 since it maps Custom(0) to CUSTOM_ZERO.
+        // unwrap_or instead of unwrap avoids a panic branch
+        Self(NonZeroU64::new(u64::from(value)).unwrap_or(CUSTOM_ZERO))
+    }
+}
+
+impl TryFrom<ProgramError> for SolProgramError {
+    type Error = ProgramError;
+
+    /// Errs if `!value.is_canonical()`
+    #[inline]
+    fn try_from(value: ProgramError) -> Result<Self, Self::Error> {
+        if value.is_canonical() {
+            Ok(Self::from(value.0.get()))
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl From<BuiltInProgramError> for SolProgramError {
+    #[inline]
+    fn from(value: BuiltInProgramError) -> Self {
+        Self::from(value.into_u64())
+    }
+}
+
+impl TryFrom<SolProgramError> for BuiltInProgramError {
+    /// The custom error code
+    type Error = u32;
+
+    #[inline]
+    fn try_from(value: SolProgramError) -> Result<Self, Self::Error> {
+        ProgramError::from(value).try_into_builtin()
+    }
+}
+
+/// Non-canonical values, see [`ProgramError::is_canonical`], map to [`InstructionError::InvalidError`]
+impl From<ProgramError> for InstructionError {
+    #[inline]
+    fn from(value: ProgramError) -> Self {
+        Self::from(value.0.get())
+    }
+}
+
+impl TryFrom<InstructionError> for ProgramError {
+    type Error = InstructionError;
+
+    #[inline]
+    fn try_from(value: InstructionError) -> Result<Self, Self::Error> {
+        SolProgramError::try_from(value).map(Self::from)
+    }
+}
+
+impl From<BuiltInProgramError> for InstructionError {
+    #[inline]
+    fn from(value: BuiltInProgramError) -> Self {
+        Self::from(value.into_u64())
+    }
+}
+
+impl TryFrom<InstructionError> for BuiltInProgramError {
+    type Error = InstructionError;
+
+    #[inline]
+    fn try_from(value: InstructionError) -> Result<Self, Self::Error> {
+        ProgramError::try_from(value.clone())?
+            .try_into_builtin()
+            .map_err(|_| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// All builtins are the 25 consecutive values after CUSTOM_ZERO
+    fn any_builtin() -> impl Strategy<Value = BuiltInProgramError> {
+        (2u64..=26).prop_map(|i| BuiltInProgramError::try_from_u64(i << 32).unwrap())
+    }
+
+    #[test]
+    fn custom_zero() {
+        let zero = ProgramError::custom(0);
+        assert_eq!(zero.0, CUSTOM_ZERO);
+        assert_eq!(
+            SolProgramError::try_from(zero),
+            Ok(SolProgramError::Custom(0))
+        );
+        assert_eq!(u64::from(SolProgramError::Custom(0)), CUSTOM_ZERO.get());
+        assert_eq!(ProgramError::from(SolProgramError::Custom(0)), zero);
+        assert_eq!(InstructionError::from(zero), InstructionError::Custom(0));
+        assert_eq!(
+            ProgramError::try_from(InstructionError::Custom(0)),
+            Ok(zero)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn builtin_round_trip(b in any_builtin()) {
+            let sol = SolProgramError::from(b);
+            prop_assert_eq!(u64::from(sol.clone()), b.into_u64());
+            prop_assert_eq!(BuiltInProgramError::try_from(sol.clone()), Ok(b));
+            prop_assert_eq!(ProgramError::from(sol.clone()), ProgramError::from(b));
+            prop_assert_eq!(SolProgramError::try_from(ProgramError::from(b)), Ok(sol));
+
+            let ie = InstructionError::from(b);
+            prop_assert_eq!(BuiltInProgramError::try_from(ie.clone()), Ok(b));
+            prop_assert_eq!(ProgramError::try_from(ie), Ok(ProgramError::from(b)));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn custom_round_trip(code: u32) {
+            let e = ProgramError::custom(code);
+            prop_assert!(e.is_canonical());
+
+            let sol = SolProgramError::try_from(e).unwrap();
+            prop_assert_eq!(&sol, &SolProgramError::Custom(code));
+            prop_assert_eq!(ProgramError::from(sol.clone()), e);
+            prop_assert_eq!(BuiltInProgramError::try_from(sol), Err(code));
+
+            let ie = InstructionError::from(e);
+            prop_assert_eq!(&ie, &InstructionError::Custom(code));
+            prop_assert_eq!(ProgramError::try_from(ie.clone()), Ok(e));
+            prop_assert_eq!(BuiltInProgramError::try_from(ie.clone()), Err(ie));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn non_canonical_fails(upper in 27u32.., lower: u32) {
+            let e = ProgramError(NonZeroU64::new(((upper as u64) << 32) | lower as u64).unwrap());
+            prop_assert!(!e.is_canonical());
+            prop_assert_eq!(SolProgramError::try_from(e), Err(e));
+            prop_assert_eq!(InstructionError::from(e), InstructionError::InvalidError);
+        }
+    }
+
+    #[test]
+    fn non_program_instruction_errors_fail() {
+        for ie in [
+            InstructionError::GenericError,
+            InstructionError::ProgramFailedToComplete,
+            InstructionError::InvalidError,
+        ] {
+            assert_eq!(ProgramError::try_from(ie.clone()), Err(ie.clone()));
+            assert_eq!(BuiltInProgramError::try_from(ie.clone()), Err(ie));
+        }
+    }
+}
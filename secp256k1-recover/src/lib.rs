@@ -0,0 +1,259 @@
+//! secp256k1 public key recovery via the `sol_secp256k1_recover` syscall.
+//!
+//! Off-chain, this falls back to [`libsecp256k1`], the same library used by the runtime, for testing.
+//!
+//! ## Signature malleability
+//!
+//! The syscall accepts both low and high-S signatures, so for any valid signature,
+//! another valid signature for the same message and pubkey can be trivially derived.
+//! Do not use signatures as unique identifiers e.g. for replay protection without
+//! first checking that S is in the lower half of the curve order.
+
+#![cfg_attr(not(test), no_std)]
+#![allow(unexpected_cfgs)]
+
+use core::{fmt::Display, mem::MaybeUninit};
+
+pub const SECP256K1_HASH_LEN: usize = 32;
+
+pub const SECP256K1_SIG_LEN: usize = 64;
+
+/// Uncompressed public key, without the leading `0x04` prefix byte
+pub const SECP256K1_PUBKEY_LEN: usize = 64;
+
+pub const ETH_ADDR_LEN: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Secp256k1RecoverError {
+    InvalidHash,
+    InvalidRecoveryId,
+    InvalidSignature,
+}
+
+impl Secp256k1RecoverError {
+    /// Returns the syscall's return code for this error
+    #[inline(always)]
+    pub const fn into_u64(self) -> u64 {
+        match self {
+            Self::InvalidHash => 1,
+            Self::InvalidRecoveryId => 2,
+            Self::InvalidSignature => 3,
+        }
+    }
+
+    /// Returns `None` if `code` is not one of the syscall's error return codes
+    #[inline(always)]
+    pub const fn try_from_u64(code: u64) -> Option<Self> {
+        Some(match code {
+            1 => Self::InvalidHash,
+            2 => Self::InvalidRecoveryId,
+            3 => Self::InvalidSignature,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for Secp256k1RecoverError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::InvalidHash => "InvalidHash",
+            Self::InvalidRecoveryId => "InvalidRecoveryId",
+            Self::InvalidSignature => "InvalidSignature",
+        })
+    }
+}
+
+impl core::error::Error for Secp256k1RecoverError {}
+
+impl From<Secp256k1RecoverError> for u64 {
+    #[inline(always)]
+    fn from(value: Secp256k1RecoverError) -> Self {
+        value.into_u64()
+    }
+}
+
+/// Recovers the public key that produced `sig` over the message hash `hash`.
+///
+/// `recovery_id` is in `0..=3`. For ethereum signatures, this is `v - 27`.
+#[inline]
+pub fn secp256k1_recover(
+    hash: &[u8; SECP256K1_HASH_LEN],
+    recovery_id: u8,
+    sig: &[u8; SECP256K1_SIG_LEN],
+) -> Result<[u8; SECP256K1_PUBKEY_LEN], Secp256k1RecoverError> {
+    let mut pubkey = MaybeUninit::uninit();
+    secp256k1_recover_to(hash, recovery_id, sig, &mut pubkey)?;
+    Ok(unsafe { pubkey.assume_init() })
+}
+
+/// This is potentially more compute-efficient than [`secp256k1_recover`] by using out-pointers.
+///
+/// The compiler has proven to be unable to optimize away the move/copy in
+/// `MaybeUninit::assume_init()` in many cases, especially when the returned value is
+/// only dropped at entrypoint exit.
+///
+/// A memory leak can potentially occur if the initialized value in the MaybeUninits
+/// are not dropped, but [u8; 64] is Copy so its fine
+#[inline]
+pub fn secp256k1_recover_to<'dst>(
+    hash: &[u8; SECP256K1_HASH_LEN],
+    recovery_id: u8,
+    sig: &[u8; SECP256K1_SIG_LEN],
+    pubkey: &'dst mut MaybeUninit<[u8; SECP256K1_PUBKEY_LEN]>,
+) -> Result<&'dst mut [u8; SECP256K1_PUBKEY_LEN], Secp256k1RecoverError> {
+    #[cfg(target_os = "solana")]
+    {
+        let result = unsafe {
+            jiminy_syscall::sol_secp256k1_recover(
+                hash.as_ptr(),
+                recovery_id as u64,
+                sig.as_ptr(),
+                pubkey.as_mut_ptr().cast(),
+            )
+        };
+        match result {
+            0 => Ok(unsafe { pubkey.assume_init_mut() }),
+            // syscall only ever returns error codes 1-3
+            code => Err(match Secp256k1RecoverError::try_from_u64(code) {
+                Some(e) => e,
+                None => Secp256k1RecoverError::InvalidSignature,
+            }),
+        }
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        // same as agave's syscall impl
+        let message = libsecp256k1::Message::parse(hash);
+        let recovery_id = libsecp256k1::RecoveryId::parse(recovery_id)
+            .map_err(|_| Secp256k1RecoverError::InvalidRecoveryId)?;
+        let sig = libsecp256k1::Signature::parse_standard(sig)
+            .map_err(|_| Secp256k1RecoverError::InvalidSignature)?;
+        let recovered = libsecp256k1::recover(&message, &sig, &recovery_id)
+            .map_err(|_| Secp256k1RecoverError::InvalidSignature)?
+            .serialize();
+        // first byte is 0x04 uncompressed prefix
+        let (_prefix, rest) = recovered.split_first().unwrap();
+        Ok(pubkey.write(*rest.first_chunk().unwrap()))
+    }
+}
+
+/// Returns the ethereum address of `pubkey`, the last 20 bytes of its keccak256 hash
+#[inline]
+pub fn eth_address(pubkey: &[u8; SECP256K1_PUBKEY_LEN]) -> [u8; ETH_ADDR_LEN] {
+    let hash = jiminy_hash::keccak256(&[pubkey]);
+    // unwrap-safety: 32 > 20
+    *hash.last_chunk().unwrap()
+}
+
+/// Convenience function that recovers the ethereum address that produced `sig`
+/// over the message hash `hash`.
+///
+/// See [`secp256k1_recover`].
+#[inline]
+pub fn secp256k1_recover_eth_address(
+    hash: &[u8; SECP256K1_HASH_LEN],
+    recovery_id: u8,
+    sig: &[u8; SECP256K1_SIG_LEN],
+) -> Result<[u8; ETH_ADDR_LEN], Secp256k1RecoverError> {
+    let mut pubkey = MaybeUninit::uninit();
+    let pubkey = secp256k1_recover_to(hash, recovery_id, sig, &mut pubkey)?;
+    Ok(eth_address(pubkey))
+}
+
+#[cfg(test)]
+mod tests {
+    use libsecp256k1::{sign, Message, PublicKey, SecretKey};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn eth_address_known_vector() {
+        // secp256k1 generator, privkey = 1
+        let pubkey = [
+            0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87,
+            0x0b, 0x07, 0x02, 0x9b, 0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b,
+            0x16, 0xf8, 0x17, 0x98, 0x48, 0x3a, 0xda, 0x77, 0x26, 0xa3, 0xc4, 0x65, 0x5d, 0xa4,
+            0xfb, 0xfc, 0x0e, 0x11, 0x08, 0xa8, 0xfd, 0x17, 0xb4, 0x48, 0xa6, 0x85, 0x54, 0x19,
+            0x9c, 0x47, 0xd0, 0x8f, 0xfb, 0x10, 0xd4, 0xb8,
+        ];
+        assert_eq!(
+            eth_address(&pubkey),
+            [
+                0x7e, 0x5f, 0x45, 0x52, 0x09, 0x1a, 0x69, 0x12, 0x5d, 0x5d, 0xfc, 0xb7, 0xb8, 0xc2,
+                0x65, 0x90, 0x29, 0x39, 0x5b, 0xdf,
+            ]
+        );
+    }
+
+    #[test]
+    fn error_code_round_trip() {
+        for e in [
+            Secp256k1RecoverError::InvalidHash,
+            Secp256k1RecoverError::InvalidRecoveryId,
+            Secp256k1RecoverError::InvalidSignature,
+        ] {
+            assert_eq!(Secp256k1RecoverError::try_from_u64(e.into_u64()), Some(e));
+        }
+        for code in [0, 4, u64::MAX] {
+            assert_eq!(Secp256k1RecoverError::try_from_u64(code), None);
+        }
+    }
+
+    fn any_secret_key() -> impl Strategy<Value = SecretKey> {
+        any::<[u8; 32]>().prop_filter_map("invalid secret key", |b| SecretKey::parse(&b).ok())
+    }
+
+    proptest! {
+        #[test]
+        fn recovers_signer(sk in any_secret_key(), hash: [u8; 32]) {
+            let (sig, recovery_id) = sign(&Message::parse(&hash), &sk);
+            let expected = PublicKey::from_secret_key(&sk).serialize();
+
+            let pubkey = secp256k1_recover(&hash, recovery_id.serialize(), &sig.serialize()).unwrap();
+            prop_assert_eq!(pubkey.as_slice(), &expected[1..]);
+            prop_assert_eq!(
+                secp256k1_recover_eth_address(&hash, recovery_id.serialize(), &sig.serialize()).unwrap(),
+                eth_address(&pubkey)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn invalid_recovery_id(hash: [u8; 32], recovery_id in 4u8.., sig: [u8; 64]) {
+            prop_assert_eq!(
+                secp256k1_recover(&hash, recovery_id, &sig),
+                Err(Secp256k1RecoverError::InvalidRecoveryId)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn wrong_recovery_id_recovers_different_key(sk in any_secret_key(), hash: [u8; 32]) {
+            let (sig, recovery_id) = sign(&Message::parse(&hash), &sk);
+            let expected = PublicKey::from_secret_key(&sk).serialize();
+
+            let wrong_id = recovery_id.serialize() ^ 1;
+            if let Ok(pubkey) = secp256k1_recover(&hash, wrong_id, &sig.serialize()) {
+                prop_assert_ne!(pubkey.as_slice(), &expected[1..]);
+            }
+        }
+    }
+
+    #[test]
+    fn zero_sig_invalid() {
+        assert_eq!(
+            secp256k1_recover(&[1; 32], 0, &[0; 64]),
+            Err(Secp256k1RecoverError::InvalidSignature)
+        );
+        // r and s >= curve order
+        assert_eq!(
+            secp256k1_recover(&[1; 32], 0, &[0xff; 64]),
+            Err(Secp256k1RecoverError::InvalidSignature)
+        );
+    }
+}
@@ -0,0 +1,255 @@
+//! Introspection of successfully processed sibling instructions
+//! via the `sol_get_processed_sibling_instruction` syscall.
+//!
+//! The processed sibling instruction list is a reverse-ordered list of
+//! successfully processed instructions at the same stack height as the
+//! currently executing instruction. For example, given the call flow:
+//!
+//! ```text
+//! A
+//! B -> C -> D
+//! B -> E
+//! B -> F
+//! ```
+//!
+//! B's processed sibling instruction list is `[A]`,
+//! F's processed sibling instruction list is `[E, C]`.
+
+#![cfg_attr(not(test), no_std)]
+#![allow(unexpected_cfgs)]
+
+use core::mem::MaybeUninit;
+
+/// Stack height when processing transaction-level instructions
+pub const TRANSACTION_LEVEL_STACK_HEIGHT: usize = 1;
+
+/// Returns the current stack height.
+///
+/// Transaction-level instructions are height [`TRANSACTION_LEVEL_STACK_HEIGHT`],
+/// the first invoked inner instruction is height `TRANSACTION_LEVEL_STACK_HEIGHT + 1`, and so forth.
+#[inline]
+pub fn stack_height() -> usize {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        jiminy_syscall::sol_get_stack_height() as usize
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    unreachable!()
+}
+
+/// Lengths of a processed sibling instruction.
+///
+/// Same layout as the syscall's `ProcessedSiblingInstruction` in-out parameter.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct SiblingInstrLens {
+    // u64s instead of usizes to match the
+    // syscall's expected layout on all targets
+    data_len: u64,
+    accounts_len: u64,
+}
+
+impl SiblingInstrLens {
+    #[inline]
+    pub const fn data_len(&self) -> usize {
+        self.data_len as usize
+    }
+
+    #[inline]
+    pub const fn accounts_len(&self) -> usize {
+        self.accounts_len as usize
+    }
+}
+
+/// A processed sibling instruction.
+///
+/// `MAX_DATA_LEN` and `MAX_ACCOUNTS` determine the size of the buffers that
+/// the instruction's data and accounts are copied into.
+#[derive(Debug, Clone, Copy)]
+pub struct SiblingInstr<const MAX_DATA_LEN: usize, const MAX_ACCOUNTS: usize> {
+    lens: SiblingInstrLens,
+    program_id: MaybeUninit<[u8; 32]>,
+    data: [MaybeUninit<u8>; MAX_DATA_LEN],
+    accounts: [MaybeUninit<SiblingInstrAcc>; MAX_ACCOUNTS],
+}
+
+// Accessors
+impl<const MAX_DATA_LEN: usize, const MAX_ACCOUNTS: usize>
+    SiblingInstr<MAX_DATA_LEN, MAX_ACCOUNTS>
+{
+    #[inline]
+    pub const fn lens(&self) -> &SiblingInstrLens {
+        &self.lens
+    }
+
+    #[inline]
+    pub const fn program_id(&self) -> &[u8; 32] {
+        unsafe { self.program_id.assume_init_ref() }
+    }
+
+    #[inline]
+    pub const fn data(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.data.as_ptr().cast(), self.lens.data_len()) }
+    }
+
+    #[inline]
+    pub const fn accounts(&self) -> &[SiblingInstrAcc] {
+        unsafe {
+            core::slice::from_raw_parts(self.accounts.as_ptr().cast(), self.lens.accounts_len())
+        }
+    }
+}
+
+impl<const MAX_DATA_LEN: usize, const MAX_ACCOUNTS: usize>
+    SiblingInstr<MAX_DATA_LEN, MAX_ACCOUNTS>
+{
+    /// Returns the `index`th most recently processed sibling instruction,
+    /// `index = 0` being the most recent one.
+    ///
+    /// Returns
+    /// - `Ok(None)` if there is no such sibling instruction
+    /// - `Err` with the sibling instruction's lengths if its data or accounts do not fit into
+    ///   `MAX_DATA_LEN` or `MAX_ACCOUNTS`, since the syscall does not support truncation
+    #[inline]
+    pub fn get(index: usize) -> Result<Option<Self>, SiblingInstrLens> {
+        let mut res = MaybeUninit::uninit();
+        if Self::overwrite(&mut res, index)?.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(unsafe { res.assume_init() }))
+    }
+
+    /// Potentially more compute-efficient version [`Self::get`] by using out-pointers.
+    /// Overwrites the old data in `this`.
+    ///
+    /// The syscall only copies the instruction if the lengths passed in
+    /// match the instruction's exactly, so this makes one syscall to fetch the lengths
+    /// and another to copy the instruction, unless the instruction has
+    /// no data and no accounts.
+    #[inline]
+    pub fn overwrite(
+        this: &mut MaybeUninit<Self>,
+        index: usize,
+    ) -> Result<Option<&mut Self>, SiblingInstrLens> {
+        #[cfg(target_os = "solana")]
+        {
+            use core::ptr::addr_of_mut;
+
+            let this_ptr = this.as_mut_ptr();
+            let lens_ptr = unsafe { addr_of_mut!((*this_ptr).lens) };
+            let syscall = || unsafe {
+                jiminy_syscall::sol_get_processed_sibling_instruction(
+                    index as u64,
+                    lens_ptr.cast(),
+                    addr_of_mut!((*this_ptr).program_id).cast(),
+                    addr_of_mut!((*this_ptr).data).cast(),
+                    addr_of_mut!((*this_ptr).accounts).cast(),
+                )
+            };
+
+            unsafe { lens_ptr.write(SiblingInstrLens::default()) };
+            if syscall() == 0 {
+                return Ok(None);
+            }
+            // syscall overwrote lens with the instruction's actual lengths
+            let lens = unsafe { lens_ptr.read() };
+            if lens != SiblingInstrLens::default() {
+                if lens.data_len() > MAX_DATA_LEN || lens.accounts_len() > MAX_ACCOUNTS {
+                    return Err(lens);
+                }
+                syscall();
+            }
+            Ok(Some(unsafe { this.assume_init_mut() }))
+        }
+
+        #[cfg(not(target_os = "solana"))]
+        {
+            core::hint::black_box((this, index));
+            unreachable!()
+        }
+    }
+}
+
+/// Uses [`SiblingInstr::get`] under the hood, and is probably more ergonomic
+/// to just use that since the const generics can be inferred in some contexts
+#[inline]
+pub fn get_processed_sibling_instr<const MAX_DATA_LEN: usize, const MAX_ACCOUNTS: usize>(
+    index: usize,
+) -> Result<Option<SiblingInstr<MAX_DATA_LEN, MAX_ACCOUNTS>>, SiblingInstrLens> {
+    SiblingInstr::get(index)
+}
+
+const SIBLING_INSTR_ACC_LEN: usize = 34;
+
+/// An instruction account of a processed sibling instruction.
+///
+/// Same layout as solana's `AccountMeta`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SiblingInstrAcc([u8; SIBLING_INSTR_ACC_LEN]);
+
+impl SiblingInstrAcc {
+    #[inline]
+    pub const fn as_buf(&self) -> &[u8; SIBLING_INSTR_ACC_LEN] {
+        &self.0
+    }
+
+    #[inline]
+    pub const fn key(&self) -> &[u8; 32] {
+        // safety: self.length is 34, so [..32] is [u8; 32]. align = 1
+        unsafe { &*self.0.as_ptr().cast() }
+    }
+
+    #[inline]
+    pub const fn is_signer(&self) -> bool {
+        self.0[32] != 0
+    }
+
+    #[inline]
+    pub const fn is_writable(&self) -> bool {
+        self.0[33] != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::{align_of, size_of};
+
+    use proptest::prelude::*;
+    use solana_instruction::{AccountMeta, ProcessedSiblingInstruction};
+    use solana_pubkey::Pubkey;
+
+    use super::*;
+
+    #[test]
+    fn lens_layout_eq_solana() {
+        assert_eq!(
+            size_of::<SiblingInstrLens>(),
+            size_of::<ProcessedSiblingInstruction>()
+        );
+        assert_eq!(
+            align_of::<SiblingInstrLens>(),
+            align_of::<ProcessedSiblingInstruction>()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn acc_layout_eq_solana(key: [u8; 32], is_signer: bool, is_writable: bool) {
+            prop_assert_eq!(size_of::<SiblingInstrAcc>(), size_of::<AccountMeta>());
+            prop_assert!(align_of::<SiblingInstrAcc>() <= align_of::<AccountMeta>());
+
+            let sol = AccountMeta {
+                pubkey: Pubkey::new_from_array(key),
+                is_signer,
+                is_writable,
+            };
+            // safety: AccountMeta is repr(C) with no padding
+            let us: &SiblingInstrAcc = unsafe { &*core::ptr::from_ref(&sol).cast() };
+            prop_assert_eq!(us.key(), &key);
+            prop_assert_eq!(us.is_signer(), is_signer);
+            prop_assert_eq!(us.is_writable(), is_writable);
+        }
+    }
+}
@@ -49,3 +49,88 @@ define_code!(SOL_GET_LAST_RESTART_SLOT, 38);
 define_code!(SOL_GET_EPOCH_REWARDS_SYSVAR, 39);
 define_code!(SOL_GET_FEES_SYSVAR, 40);
 define_code!(SOL_GET_RENT_SYSVAR, 41);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Transcribed from SIMD-0178 independently of the consts above.
+    /// With `static-syscalls`, these are the addresses syscalls are called at.
+    const SIMD_0178: [(&str, u32, u32); 41] = [
+        ("abort", ABORT, 1),
+        ("sol_panic_", SOL_PANIC, 2),
+        ("sol_memcpy_", SOL_MEMCPY_, 3),
+        ("sol_memmove_", SOL_MEMMOVE_, 4),
+        ("sol_memset_", SOL_MEMSET_, 5),
+        ("sol_memcmp_", SOL_MEMCMP_, 6),
+        ("sol_log_", SOL_LOG_, 7),
+        ("sol_log_64_", SOL_LOG_64_, 8),
+        ("sol_log_pubkey", SOL_LOG_PUBKEY, 9),
+        ("sol_log_compute_units_", SOL_LOG_COMPUTE_UNITS_, 10),
+        ("sol_alloc_free_", SOL_ALLOC_FREE_, 11),
+        ("sol_invoke_signed_c", SOL_INVOKE_SIGNED_C, 12),
+        ("sol_invoke_signed_rust", SOL_INVOKE_SIGNED_RUST, 13),
+        ("sol_set_return_data", SOL_SET_RETURN_DATA, 14),
+        ("sol_get_return_data", SOL_GET_RETURN_DATA, 15),
+        ("sol_log_data", SOL_LOG_DATA, 16),
+        ("sol_sha256", SOL_SHA256, 17),
+        ("sol_keccak256", SOL_KECCAK256, 18),
+        ("sol_secp256k1_recover", SOL_SECP256K1_RECOVER, 19),
+        ("sol_blake3", SOL_BLAKE3, 20),
+        ("sol_poseidon", SOL_POSEIDON, 21),
+        (
+            "sol_get_processed_sibling_instruction",
+            SOL_GET_PROCESSED_SIBLING_INSTRUCTION,
+            22,
+        ),
+        ("sol_get_stack_height", SOL_GET_STACK_HEIGHT, 23),
+        ("sol_curve_validate_point", SOL_CURVE_VALIDATE_POINT, 24),
+        ("sol_curve_group_op", SOL_CURVE_GROUP_OP, 25),
+        ("sol_curve_multiscalar_mul", SOL_CURVE_MULTISCALAR_MUL, 26),
+        ("sol_curve_pairing_map", SOL_CURVE_PAIRING_MAP, 27),
+        ("sol_alt_bn128_group_op", SOL_ALT_BN128_GROUP_OP, 28),
+        ("sol_alt_bn128_compression", SOL_ALT_BN128_COMPRESSION, 29),
+        ("sol_big_mod_exp", SOL_BIG_MOD_EXP, 30),
+        (
+            "sol_remaining_compute_units",
+            SOL_REMAINING_COMPUTE_UNITS,
+            31,
+        ),
+        ("sol_create_program_address", SOL_CREATE_PROGRAM_ADDRESS, 32),
+        (
+            "sol_try_find_program_address",
+            SOL_TRY_FIND_PROGRAM_ADDRESS,
+            33,
+        ),
+        ("sol_get_sysvar", SOL_GET_SYSVAR, 34),
+        ("sol_get_epoch_stake", SOL_GET_EPOCH_STAKE, 35),
+        ("sol_get_clock_sysvar", SOL_GET_CLOCK_SYSVAR, 36),
+        (
+            "sol_get_epoch_schedule_sysvar",
+            SOL_GET_EPOCH_SCHEDULE_SYSVAR,
+            37,
+        ),
+        ("sol_get_last_restart_slot", SOL_GET_LAST_RESTART_SLOT, 38),
+        (
+            "sol_get_epoch_rewards_sysvar",
+            SOL_GET_EPOCH_REWARDS_SYSVAR,
+            39,
+        ),
+        ("sol_get_fees_sysvar", SOL_GET_FEES_SYSVAR, 40),
+        ("sol_get_rent_sysvar", SOL_GET_RENT_SYSVAR, 41),
+    ];
+
+    #[test]
+    fn codes_match_simd_0178() {
+        SIMD_0178.iter().for_each(|(name, code, expected)| {
+            assert_eq!(code, expected, "{name}");
+        });
+    }
+
+    #[test]
+    fn codes_unique_and_contiguous() {
+        let mut codes = SIMD_0178.map(|(_, code, _)| code);
+        codes.sort_unstable();
+        assert_eq!(codes, core::array::from_fn(|i| i as u32 + 1));
+    }
+}
@@ -3,7 +3,15 @@
 //!
 //! with
 //! - `no_std` slapped atop
-//! - `static-syscalls` feature disabled, because that doesnt seem to work yet?
+//! - `static-syscalls` as a crate feature instead of a target feature,
+//!   and using the SIMD-0178 codes in `codes.rs` instead of murmur3 hashes of the syscall names
+//!
+//! Programs targeting SBPFv3 and later should enable the `static-syscalls` feature of this crate.
+//! All other jiminy crates call syscalls through this crate, so they need no changes.
+//!
+//! Do NOT enable `static-syscalls` for targets before SBPFv3: the runtime does not
+//! resolve syscall codes there, so every syscall becomes a call to an invalid address
+//! (e.g. `0x7` for `sol_log_`) that fails at runtime instead of at build time.
 
 #![cfg_attr(not(test), no_std)]
 
@@ -13,15 +21,67 @@ mod definitions;
 pub use codes::*;
 pub use definitions::*;
 
+/// Returns true if `name` is the lowercase of `code`,
+/// used to check that each syscall is defined with its own code.
+const fn is_code_of(name: &str, code: &str) -> bool {
+    let (name, code) = (name.as_bytes(), code.as_bytes());
+    if name.len() != code.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < name.len() {
+        if name[i] != code[i].to_ascii_lowercase() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Syscalls are called by numeric code instead of by symbol relocation,
+/// as required by SBPFv3 and later.
+#[cfg(feature = "static-syscalls")]
 macro_rules! define_syscall {
-    (fn $name:ident($($arg:ident: $typ:ty),*) -> $ret:ty) => {
+    (fn $name:ident($($arg:ident: $typ:ty),*) -> $ret:ty, $code:ident) => {
+        const _: () = assert!($crate::is_code_of(stringify!($name), stringify!($code)));
+
+        #[inline]
+        pub unsafe fn $name($($arg: $typ),*) -> $ret {
+            let syscall: extern "C" fn($($arg: $typ),*) -> $ret =
+                core::mem::transmute($crate::codes::$code as usize);
+            syscall($($arg),*)
+        }
+    };
+    (fn $name:ident($($arg:ident: $typ:ty),*), $code:ident) => {
+        define_syscall!(fn $name($($arg: $typ),*) -> (), $code);
+    }
+}
+
+#[cfg(not(feature = "static-syscalls"))]
+macro_rules! define_syscall {
+    (fn $name:ident($($arg:ident: $typ:ty),*) -> $ret:ty, $code:ident) => {
+        const _: () = assert!($crate::is_code_of(stringify!($name), stringify!($code)));
+
         extern "C" {
             pub fn $name($($arg: $typ),*) -> $ret;
         }
     };
-    (fn $name:ident($($arg:ident: $typ:ty),*)) => {
-        define_syscall!(fn $name($($arg: $typ),*) -> ());
+    (fn $name:ident($($arg:ident: $typ:ty),*), $code:ident) => {
+        define_syscall!(fn $name($($arg: $typ),*) -> (), $code);
     }
 }
 
 pub(crate) use define_syscall;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_code_of_cases() {
+        assert!(is_code_of("sol_log_", "SOL_LOG_"));
+        assert!(!is_code_of("sol_log_", "SOL_LOG_64_"));
+        assert!(!is_code_of("sol_log", "SOL_LOG_"));
+        assert!(!is_code_of("SOL_LOG_", "sol_log_"));
+    }
+}
@@ -19,6 +19,8 @@ pub mod sysvar {
     pub use jiminy_sysvar::*;
 }
 
+pub mod precompiles;
+
 use core::{iter::Map, ptr, slice};
 
 use account::Account;
@@ -47,7 +49,8 @@ impl SysvarId for Instructions<'_> {
 impl<'a> Instructions<'a> {
     /// Returns `None` if `acc` is not the instructions sysvar account.
     ///
-    /// This is the only way to safely obtain this struct.
+    /// The account data is trusted to be valid, as serialized by the runtime.
+    /// Use [`Self::try_from_account_checked`] to validate it instead.
     #[inline]
     pub fn try_from_account(acc: &'a Account) -> Option<Self> {
         if *acc.key() == Self::ID {
@@ -58,6 +61,72 @@ impl<'a> Instructions<'a> {
             None
         }
     }
+
+    /// Returns `None` if `acc` is not the instructions sysvar account
+    /// or if its data is not a valid serialized instructions sysvar.
+    ///
+    /// Validation checks that the offset table and every instruction's
+    /// accounts and data lengths are within, and exactly span, `acc.data()`,
+    /// and that the current ix index is in bounds,
+    /// at the cost of iterating through all instructions once.
+    #[inline]
+    pub fn try_from_account_checked(acc: &'a Account) -> Option<Self> {
+        Self::try_from_account(acc).filter(|s| is_valid_data(s.acc_data))
+    }
+}
+
+/// Checks that `acc_data`:
+/// - is 2-byte aligned, since the len and offset table are read via pointer casts
+/// - contains the len, offset table and current ix index
+/// - has an offset table whose offsets point to contiguous instructions that
+///   start right after the offset table and end right before the current ix index
+/// - has a current ix index < len
+fn is_valid_data(acc_data: &[u8]) -> bool {
+    if !acc_data.as_ptr().cast::<u16>().is_aligned() {
+        return false;
+    }
+    let Some(ixs_end) = acc_data.len().checked_sub(2) else {
+        return false;
+    };
+    let Some(len) = read_u16_le(acc_data, 0) else {
+        return false;
+    };
+    let len = usize::from(len);
+
+    let mut expected_start = Instructions::OFFSET_TABLE_OFFSET + 2 * len;
+    if expected_start > ixs_end {
+        return false;
+    }
+    for i in 0..len {
+        // unwrap-safety: bounds checked above
+        let offset = read_u16_le(acc_data, Instructions::OFFSET_TABLE_OFFSET + 2 * i).unwrap();
+        if usize::from(offset) != expected_start {
+            return false;
+        }
+        match intro_instr_end(acc_data, expected_start) {
+            Some(end) if end <= ixs_end => expected_start = end,
+            _ => return false,
+        }
+    }
+    expected_start == ixs_end
+        && read_u16_le(acc_data, ixs_end).is_some_and(|idx| usize::from(idx) < len)
+}
+
+#[inline]
+fn read_u16_le(data: &[u8], start: usize) -> Option<u16> {
+    data.get(start..)
+        .and_then(|s| s.first_chunk())
+        .map(|c| u16::from_le_bytes(*c))
+}
+
+/// Returns the end index of the instruction serialized at `acc_data[start..]`,
+/// or `None` if it runs out of bounds
+fn intro_instr_end(acc_data: &[u8], start: usize) -> Option<usize> {
+    let accounts_len = usize::from(read_u16_le(acc_data, start)?);
+    let data_len_start = start + 2 + accounts_len * INTRO_INSTR_ACC_LEN + 32;
+    let data_len = usize::from(read_u16_le(acc_data, data_len_start)?);
+    let end = data_len_start + 2 + data_len;
+    (end <= acc_data.len()).then_some(end)
 }
 
 /// instructions length
@@ -134,64 +203,110 @@ pub struct IntroInstr<'a> {
 pub type IntroInstrIter<'a, F> = Map<slice::Iter<'a, u16>, F>;
 
 /// Instruction unpacking
-impl Instructions<'_> {
+impl<'a> Instructions<'a> {
     #[inline]
-    pub fn iter<'a>(&'a self) -> IntroInstrIter<'a, impl Fn(&u16) -> IntroInstr<'a> + 'a> {
-        let unpack_ix = |offset: &u16| {
-            let start = usize::from(*offset);
-            let mut end = start;
-
-            // first 2-bytes are ix accounts len.
-            // cannot guarantee 2-byte alignment due to arbitrary ix data length
-            //
-            // index-safety: offset table should give valid offsets
-            let accounts_len = match self.acc_data[end..end + 2] {
-                [u0, u1] => usize::from(u16::from_le_bytes([u0, u1])),
-                _ => unreachable!(),
-            };
-            end += 2;
-
-            // each account input is 33 bytes:
-            // InstructionsAccountPerms + pubkey
-            end += accounts_len * INTRO_INSTR_ACC_LEN;
-
-            // next 32 bytes are program ID
-            end += 32;
-
-            // next 2 bytes are data_len
-            //
-            // index-safety: offset table should give valid offsets
-            let data_len = match self.acc_data[end..end + 2] {
-                [u0, u1] => usize::from(u16::from_le_bytes([u0, u1])),
-                _ => unreachable!(),
-            };
-            end += 2;
-
-            // last bytes are data
-            end += data_len;
-
-            // if it exists, the next instruction follows and `end`
-            // is the next entry in the offset_table since its the start
-            // of the next instruction.
-            // Or if this is the last instruction, then the current ix index follows
-
-            IntroInstr {
-                buf: &self.acc_data[start..end],
-                accounts_len,
-                data_len,
-            }
+    pub fn iter(&self) -> IntroInstrIter<'_, impl Fn(&u16) -> IntroInstr<'a> + '_> {
+        let unpack_ix = |offset: &u16| self.unpack_at(*offset);
+        self.offset_table().iter().map(unpack_ix)
+    }
+
+    /// Unpacks the instruction starting at `offset`, an entry in the offset table
+    #[inline]
+    fn unpack_at(&self, offset: u16) -> IntroInstr<'a> {
+        let start = usize::from(offset);
+        let mut end = start;
+
+        // first 2-bytes are ix accounts len.
+        // cannot guarantee 2-byte alignment due to arbitrary ix data length
+        //
+        // index-safety: offset table should give valid offsets
+        let accounts_len = match self.acc_data[end..end + 2] {
+            [u0, u1] => usize::from(u16::from_le_bytes([u0, u1])),
+            _ => unreachable!(),
         };
+        end += 2;
 
-        self.offset_table().iter().map(unpack_ix)
+        // each account input is 33 bytes:
+        // InstructionsAccountPerms + pubkey
+        end += accounts_len * INTRO_INSTR_ACC_LEN;
+
+        // next 32 bytes are program ID
+        end += 32;
+
+        // next 2 bytes are data_len
+        //
+        // index-safety: offset table should give valid offsets
+        let data_len = match self.acc_data[end..end + 2] {
+            [u0, u1] => usize::from(u16::from_le_bytes([u0, u1])),
+            _ => unreachable!(),
+        };
+        end += 2;
+
+        // last bytes are data
+        end += data_len;
+
+        // if it exists, the next instruction follows and `end`
+        // is the next entry in the offset_table since its the start
+        // of the next instruction.
+        // Or if this is the last instruction, then the current ix index follows
+
+        IntroInstr {
+            buf: &self.acc_data[start..end],
+            accounts_len,
+            data_len,
+        }
+    }
+}
+
+/// Random access
+impl<'a> Instructions<'a> {
+    /// Returns the instruction at index `idx` of the transaction,
+    /// `None` if out of bounds
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<IntroInstr<'a>> {
+        self.offset_table()
+            .get(idx)
+            .map(|offset| self.unpack_at(*offset))
+    }
+
+    /// Returns the instruction at index [`Self::current_idx`] + `offset` of the transaction,
+    /// `None` if out of bounds
+    #[inline]
+    pub fn get_relative(&self, offset: i64) -> Option<IntroInstr<'a>> {
+        let idx = (self.current_idx_u16() as i64).checked_add(offset)?;
+        self.get(usize::try_from(idx).ok()?)
+    }
+
+    /// Returns the currently executing instruction.
+    ///
+    /// Only `None` if the account data is malformed,
+    /// which cannot happen if `self` was created with [`Self::try_from_account_checked`].
+    #[inline]
+    pub fn current(&self) -> Option<IntroInstr<'a>> {
+        self.get(self.current_idx())
+    }
+
+    /// Returns the instruction right after the currently executing one,
+    /// `None` if the current instruction is the last one
+    #[inline]
+    pub fn next(&self) -> Option<IntroInstr<'a>> {
+        self.get_relative(1)
+    }
+
+    /// Returns the instruction right before the currently executing one,
+    /// `None` if the current instruction is the first one
+    #[inline]
+    pub fn prev(&self) -> Option<IntroInstr<'a>> {
+        self.get_relative(-1)
     }
 }
 
 /// Individual instruction accessors
-impl IntroInstr<'_> {
+impl<'a> IntroInstr<'a> {
     const ACCOUNTS_OFFSET: usize = 2;
 
     #[inline]
-    pub const fn accounts(&self) -> &[IntroInstrAcc] {
+    pub const fn accounts(&self) -> &'a [IntroInstrAcc] {
         // safety: IntroInstrAcc has no alignment requirements,
         // data serialized by the runtime should be valid
         unsafe {
@@ -208,7 +323,7 @@ impl IntroInstr<'_> {
     }
 
     #[inline]
-    pub const fn program_id(&self) -> &[u8; 32] {
+    pub const fn program_id(&self) -> &'a [u8; 32] {
         // safety: [u8; 32] has no alignment requirements,
         // data serialized by the runtime should be valid
         unsafe { &*self.buf.as_ptr().add(self.program_id_offset()).cast() }
@@ -221,7 +336,7 @@ impl IntroInstr<'_> {
     }
 
     #[inline]
-    pub const fn data(&self) -> &[u8] {
+    pub const fn data(&self) -> &'a [u8] {
         // safety: &[u8] has no alignment requirements,
         // data serialized by the runtime should be valid
         unsafe {
@@ -307,7 +422,7 @@ mod tests {
         )
     }
 
-    fn any_ix() -> impl Strategy<Value = Instruction> {
+    pub(crate) fn any_ix() -> impl Strategy<Value = Instruction> {
         (
             any::<[u8; 32]>(),
             vec(any_meta(), 0..42),
@@ -320,6 +435,39 @@ mod tests {
             })
     }
 
+    pub(crate) fn sol_instructions_data(ixs: &[Instruction]) -> Vec<u8> {
+        construct_instructions_data(
+            ixs.iter()
+                .map(|instruction| BorrowedInstruction {
+                    program_id: &instruction.program_id,
+                    accounts: instruction
+                        .accounts
+                        .iter()
+                        .map(|meta| BorrowedAccountMeta {
+                            pubkey: &meta.pubkey,
+                            is_signer: meta.is_signer,
+                            is_writable: meta.is_writable,
+                        })
+                        .collect(),
+                    data: &instruction.data,
+                })
+                .collect::<Vec<_>>()
+                .as_slice(),
+        )
+    }
+
+    fn assert_ix_eq(u: &IntroInstr, s: &Instruction) -> Result<(), TestCaseError> {
+        prop_assert_eq!(u.program_id(), s.program_id.as_array());
+        prop_assert_eq!(u.data(), s.data.as_slice());
+        prop_assert_eq!(u.accounts().len(), s.accounts.len());
+        for (ua, sa) in u.accounts().iter().zip(&s.accounts) {
+            prop_assert_eq!(ua.key(), sa.pubkey.as_array());
+            prop_assert_eq!(ua.flags().is_signer(), sa.is_signer);
+            prop_assert_eq!(ua.flags().is_writable(), sa.is_writable);
+        }
+        Ok(())
+    }
+
     proptest! {
         // TODO: this test is dependent on host machine being little-endian
         #[test]
@@ -328,25 +476,7 @@ mod tests {
             current_ix_idx: u16,
         ) {
             // data should be 8-byte aligned (OS pls)
-            let mut data = construct_instructions_data(
-                ixs
-                    .iter()
-                    .map(|instruction| BorrowedInstruction {
-                        program_id: &instruction.program_id,
-                        accounts: instruction
-                            .accounts
-                            .iter()
-                            .map(|meta| BorrowedAccountMeta {
-                                pubkey: &meta.pubkey,
-                                is_signer: meta.is_signer,
-                                is_writable: meta.is_writable,
-                            })
-                            .collect(),
-                        data: &instruction.data,
-                    })
-                    .collect::<Vec<_>>()
-                    .as_slice(),
-            );
+            let mut data = sol_instructions_data(&ixs);
             *data.split_last_chunk_mut().unwrap().1 = current_ix_idx.to_le_bytes();
 
             let us = Instructions { acc_data: &data };
@@ -355,16 +485,125 @@ mod tests {
 
             prop_assert_eq!(us.len(), ixs.len());
 
-            for (u, s) in us.iter().zip(ixs) {
-                prop_assert_eq!(u.program_id(), s.program_id.as_array());
-                prop_assert_eq!(u.data(), s.data);
-                prop_assert_eq!(u.accounts().len(), s.accounts.len());
-                for (ua, sa) in u.accounts().iter().zip(s.accounts) {
-                    prop_assert_eq!(ua.key(), sa.pubkey.as_array());
-                    prop_assert_eq!(ua.flags().is_signer(), sa.is_signer);
-                    prop_assert_eq!(ua.flags().is_writable(), sa.is_writable);
+            for (u, s) in us.iter().zip(&ixs) {
+                assert_ix_eq(&u, s)?;
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn random_access_against_sol(
+            ixs in vec(any_ix(), 1..7),
+            current_ix_idx in 0u16..7,
+            offset in -8i64..8,
+        ) {
+            let current_ix_idx = current_ix_idx % ixs.len() as u16;
+            let mut data = sol_instructions_data(&ixs);
+            *data.split_last_chunk_mut().unwrap().1 = current_ix_idx.to_le_bytes();
+
+            let us = Instructions { acc_data: &data };
+            let curr = usize::from(current_ix_idx);
+
+            for (i, s) in ixs.iter().enumerate() {
+                assert_ix_eq(&us.get(i).unwrap(), s)?;
+            }
+            prop_assert_eq!(us.get(ixs.len()), None);
+
+            assert_ix_eq(&us.current().unwrap(), &ixs[curr])?;
+            prop_assert_eq!(us.next(), us.get(curr + 1));
+            prop_assert_eq!(us.prev(), curr.checked_sub(1).and_then(|i| us.get(i)));
+
+            let expected = usize::try_from(curr as i64 + offset).ok().and_then(|i| ixs.get(i));
+            match (us.get_relative(offset), expected) {
+                (Some(u), Some(s)) => assert_ix_eq(&u, s)?,
+                (None, None) => (),
+                (u, s) => prop_assert!(false, "{u:?} {s:?}"),
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn get_relative_extreme_offsets(
+            ixs in vec(any_ix(), 0..3),
+            current_ix_idx: u16,
+            offset in prop_oneof![Just(i64::MIN), Just(i64::MAX), any::<i64>()],
+        ) {
+            let mut data = sol_instructions_data(&ixs);
+            *data.split_last_chunk_mut().unwrap().1 = current_ix_idx.to_le_bytes();
+            let us = Instructions { acc_data: &data };
+
+            let expected = (i64::from(current_ix_idx))
+                .checked_add(offset)
+                .and_then(|i| usize::try_from(i).ok())
+                .and_then(|i| us.get(i));
+            prop_assert_eq!(us.get_relative(offset), expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn sol_data_valid_iff_current_idx_in_bounds(
+            ixs in vec(any_ix(), 0..7),
+            current_ix_idx: u16,
+        ) {
+            let mut data = sol_instructions_data(&ixs);
+            *data.split_last_chunk_mut().unwrap().1 = current_ix_idx.to_le_bytes();
+            prop_assert_eq!(is_valid_data(&data), usize::from(current_ix_idx) < ixs.len());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn truncated_or_extended_data_invalid(
+            ixs in vec(any_ix(), 0..7),
+            truncate in any::<prop::sample::Index>(),
+            extend in vec(any::<u8>(), 1..8),
+        ) {
+            let data = sol_instructions_data(&ixs);
+
+            let truncated = &data[..truncate.index(data.len())];
+            prop_assert!(!is_valid_data(truncated));
+
+            let mut extended = data.clone();
+            extended.extend(extend);
+            prop_assert!(!is_valid_data(&extended));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn corrupted_data_valid_means_no_panic(
+            ixs in vec(any_ix(), 0..7),
+            corrupt_at in any::<prop::sample::Index>(),
+            corrupt_with: u8,
+        ) {
+            let mut data = sol_instructions_data(&ixs);
+            let i = corrupt_at.index(data.len());
+            data[i] = corrupt_with;
+
+            if is_valid_data(&data) {
+                let us = Instructions { acc_data: &data };
+                for ix in us.iter() {
+                    let _ = (ix.program_id(), ix.data(), ix.accounts());
                 }
+                prop_assert!(us.current().is_some());
+                let _ = (us.next(), us.prev());
             }
         }
     }
+
+    #[test]
+    fn too_short_data_invalid() {
+        // u16 array to guarantee alignment
+        let data = [0u16; 2];
+        // safety: u16 array is valid as bytes
+        let data: &[u8; 4] = unsafe { &*data.as_ptr().cast() };
+        for len in 0..4 {
+            assert!(!is_valid_data(&data[..len]));
+        }
+        // 0 instructions, so current ix idx 0 is out of bounds
+        assert!(!is_valid_data(data));
+    }
 }
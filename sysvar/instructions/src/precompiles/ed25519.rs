@@ -0,0 +1,26 @@
+use crate::{
+    precompiles::{PrecompileSig, SigVerifyIx, SIG_VERIFY_SIG_LEN},
+    IntroInstr,
+};
+
+pub const ED25519_PROGRAM_ID_STR: &str = "Ed25519SigVerify111111111111111111111111111";
+
+pub const ED25519_PROGRAM_ID: [u8; 32] = const_crypto::bs58::decode_pubkey(ED25519_PROGRAM_ID_STR);
+
+pub const ED25519_PUBKEY_LEN: usize = 32;
+
+pub type Ed25519Ix<'a> = SigVerifyIx<'a, ED25519_PUBKEY_LEN>;
+
+pub type Ed25519Sig<'a> = PrecompileSig<'a, ED25519_PUBKEY_LEN, SIG_VERIFY_SIG_LEN>;
+
+impl<'a> Ed25519Ix<'a> {
+    /// Returns `None` if `ix` is not a well-formed ed25519 precompile instruction
+    #[inline]
+    pub fn try_from_intro_instr(ix: &IntroInstr<'a>) -> Option<Self> {
+        if *ix.program_id() == ED25519_PROGRAM_ID {
+            Self::try_from_data(ix.data())
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,383 @@
+//! Zero-copy parsers for signature verification precompile instructions
+//! introspected from the instructions sysvar.
+//!
+//! If the transaction is executing, every precompile instruction in it must have succeeded,
+//! so the (pubkey, message, signature) triples resolved here have all been verified.
+//!
+//! These parsers do not check that the triples were verified
+//! against the expected pubkey and message, that is up to the caller.
+//!
+//! ## References
+//! - [agave ed25519 precompile](https://github.com/anza-xyz/agave/blob/master/precompiles/src/ed25519.rs)
+//! - [agave secp256k1 precompile](https://github.com/anza-xyz/agave/blob/master/precompiles/src/secp256k1.rs)
+//! - [agave secp256r1 precompile](https://github.com/anza-xyz/agave/blob/master/precompiles/src/secp256r1.rs)
+
+mod ed25519;
+mod secp256k1;
+mod secp256r1;
+mod sig_verify;
+
+pub use ed25519::*;
+pub use secp256k1::*;
+pub use secp256r1::*;
+pub use sig_verify::*;
+
+/// A (pubkey, message, signature) triple verified by a precompile instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrecompileSig<'a, const PK_LEN: usize, const SIG_LEN: usize> {
+    pub pubkey: &'a [u8; PK_LEN],
+    pub msg: &'a [u8],
+    pub sig: &'a [u8; SIG_LEN],
+}
+
+/// Reads the little-endian u16 at `buf[i..i + 2]`.
+///
+/// Precompile ix data has arbitrary alignment, so no pointer casting allowed.
+#[inline]
+const fn u16_le_at<const N: usize>(buf: &[u8; N], i: usize) -> u16 {
+    u16::from_le_bytes([buf[i], buf[i + 1]])
+}
+
+/// Returns `None` if out of bounds
+#[inline]
+fn data_slice(ix_data: &[u8], offset: u16, len: u16) -> Option<&[u8]> {
+    let start = usize::from(offset);
+    ix_data.get(start..start + usize::from(len))
+}
+
+/// Returns `None` if out of bounds
+#[inline]
+fn data_array<const N: usize>(ix_data: &[u8], offset: u16) -> Option<&[u8; N]> {
+    ix_data.get(usize::from(offset)..)?.first_chunk()
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+    use solana_ed25519_program::{
+        new_ed25519_instruction_with_signature, offsets_to_ed25519_instruction,
+        Ed25519SignatureOffsets,
+    };
+    use solana_instruction::Instruction;
+    use solana_pubkey::Pubkey;
+    use solana_secp256k1_program::new_secp256k1_instruction_with_signature;
+
+    use crate::{
+        tests::{any_ix, sol_instructions_data},
+        Instructions,
+    };
+
+    use super::*;
+
+    #[test]
+    fn program_ids_eq_sol() {
+        assert_eq!(
+            ED25519_PROGRAM_ID,
+            solana_sdk_ids::ed25519_program::ID.to_bytes()
+        );
+        assert_eq!(
+            SECP256K1_PROGRAM_ID,
+            solana_sdk_ids::secp256k1_program::ID.to_bytes()
+        );
+        assert_eq!(
+            SECP256R1_PROGRAM_ID,
+            solana_sdk_ids::secp256r1_program::ID.to_bytes()
+        );
+    }
+
+    /// Same as agave's precompiles' `get_data_slice()`
+    fn sol_data_slice(
+        ix_datas: &[Vec<u8>],
+        self_idx: Option<usize>,
+        ix_idx: usize,
+        offset: u16,
+        len: usize,
+    ) -> Option<&[u8]> {
+        let ix_idx = match self_idx {
+            Some(i) if ix_idx == usize::from(u16::MAX) => i,
+            _ => ix_idx,
+        };
+        let start = usize::from(offset);
+        ix_datas.get(ix_idx)?.get(start..start + len)
+    }
+
+    fn sig_verify_offsets_data(num_sigs: u8, offsets: &[Ed25519SignatureOffsets]) -> Vec<u8> {
+        let mut data = vec![num_sigs, 0];
+        for o in offsets {
+            for x in [
+                o.signature_offset,
+                o.signature_instruction_index,
+                o.public_key_offset,
+                o.public_key_instruction_index,
+                o.message_data_offset,
+                o.message_data_size,
+                o.message_instruction_index,
+            ] {
+                data.extend(x.to_le_bytes());
+            }
+        }
+        data
+    }
+
+    fn any_small_ix_idx() -> impl Strategy<Value = u16> {
+        prop_oneof![Just(u16::MAX), 0u16..8]
+    }
+
+    fn any_sig_verify_offsets() -> impl Strategy<Value = Ed25519SignatureOffsets> {
+        (
+            (0u16..1024, any_small_ix_idx()),
+            (0u16..1024, any_small_ix_idx()),
+            (0u16..1024, 0u16..1024, any_small_ix_idx()),
+        )
+            .prop_map(
+                |(
+                    (signature_offset, signature_instruction_index),
+                    (public_key_offset, public_key_instruction_index),
+                    (message_data_offset, message_data_size, message_instruction_index),
+                )| Ed25519SignatureOffsets {
+                    signature_offset,
+                    signature_instruction_index,
+                    public_key_offset,
+                    public_key_instruction_index,
+                    message_data_offset,
+                    message_data_size,
+                    message_instruction_index,
+                },
+            )
+    }
+
+    proptest! {
+        #[test]
+        fn ed25519_self_referencing(
+            before in vec(any_ix(), 0..3),
+            after in vec(any_ix(), 0..3),
+            msg in vec(any::<u8>(), 0..512),
+            sig: [u8; 64],
+            pubkey: [u8; 32],
+        ) {
+            let precompile_idx = before.len();
+            let ixs: Vec<_> = before
+                .into_iter()
+                .chain([new_ed25519_instruction_with_signature(&msg, &sig, &pubkey)])
+                .chain(after)
+                .collect();
+            let data = sol_instructions_data(&ixs);
+            let instructions = Instructions { acc_data: &data };
+
+            for (i, ix) in instructions.iter().enumerate() {
+                prop_assert_eq!(
+                    Ed25519Ix::try_from_intro_instr(&ix).is_some(),
+                    i == precompile_idx
+                );
+                prop_assert!(Secp256r1Ix::try_from_intro_instr(&ix).is_none());
+                prop_assert!(Secp256k1Ix::try_from_intro_instr(&ix).is_none());
+            }
+
+            let ix = Ed25519Ix::try_from_intro_instr(&instructions.get(precompile_idx).unwrap()).unwrap();
+            prop_assert_eq!(ix.num_sigs(), 1);
+            prop_assert_eq!(
+                ix.sig(&instructions, 0),
+                Some(Ed25519Sig { pubkey: &pubkey, msg: &msg, sig: &sig })
+            );
+            prop_assert_eq!(ix.sig(&instructions, 1), None);
+            prop_assert_eq!(ix.sigs(instructions).collect::<Vec<_>>(), vec![ix.sig(&instructions, 0)]);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ed25519_cross_ix(
+            padding in vec(any::<u8>(), 0..64),
+            msg in vec(any::<u8>(), 0..512),
+            sig: [u8; 64],
+            pubkey: [u8; 32],
+        ) {
+            // verify data is in ix 0, precompile is ix 1
+            let public_key_offset = padding.len();
+            let signature_offset = public_key_offset + 32;
+            let message_data_offset = signature_offset + 64;
+            let data_ix = Instruction {
+                program_id: Pubkey::new_from_array([1; 32]),
+                accounts: vec![],
+                data: [padding.as_slice(), &pubkey, &sig, &msg].concat(),
+            };
+            let precompile_ix = offsets_to_ed25519_instruction(&[Ed25519SignatureOffsets {
+                signature_offset: signature_offset as u16,
+                signature_instruction_index: 0,
+                public_key_offset: public_key_offset as u16,
+                public_key_instruction_index: 0,
+                message_data_offset: message_data_offset as u16,
+                message_data_size: msg.len() as u16,
+                message_instruction_index: 0,
+            }]);
+            let data = sol_instructions_data(&[data_ix, precompile_ix]);
+            let instructions = Instructions { acc_data: &data };
+
+            let ix = Ed25519Ix::try_from_intro_instr(&instructions.get(1).unwrap()).unwrap();
+            prop_assert_eq!(
+                ix.sig(&instructions, 0),
+                Some(Ed25519Sig { pubkey: &pubkey, msg: &msg, sig: &sig })
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn sig_verify_any_offsets_against_sol(
+            mut ixs in vec(any_ix(), 0..4),
+            offsets in vec(any_sig_verify_offsets(), 0..4),
+            claimed_num_sigs in 0u8..6,
+            is_r1: bool,
+        ) {
+            let precompile_idx = ixs.len();
+            ixs.push(Instruction {
+                program_id: if is_r1 {
+                    solana_sdk_ids::secp256r1_program::ID
+                } else {
+                    solana_sdk_ids::ed25519_program::ID
+                },
+                accounts: vec![],
+                data: sig_verify_offsets_data(claimed_num_sigs, &offsets),
+            });
+            let ix_datas: Vec<_> = ixs.iter().map(|ix| ix.data.clone()).collect();
+            let data = sol_instructions_data(&ixs);
+            let instructions = Instructions { acc_data: &data };
+            let intro_ix = instructions.get(precompile_idx).unwrap();
+
+            fn check<const PK_LEN: usize>(
+                ix: Option<SigVerifyIx<'_, PK_LEN>>,
+                instructions: &Instructions,
+                ix_datas: &[Vec<u8>],
+                precompile_idx: usize,
+                claimed_num_sigs: u8,
+                offsets: &[Ed25519SignatureOffsets],
+            ) -> Result<(), TestCaseError> {
+                if usize::from(claimed_num_sigs) > offsets.len() {
+                    prop_assert!(ix.is_none());
+                    return Ok(());
+                }
+                let ix = ix.unwrap();
+                prop_assert_eq!(ix.num_sigs(), usize::from(claimed_num_sigs));
+                for (i, o) in offsets.iter().take(ix.num_sigs()).enumerate() {
+                    let slice = |ix_idx: u16, offset: u16, len: usize| {
+                        sol_data_slice(ix_datas, Some(precompile_idx), usize::from(ix_idx), offset, len)
+                    };
+                    let expected = slice(o.public_key_instruction_index, o.public_key_offset, PK_LEN)
+                        .zip(slice(o.message_instruction_index, o.message_data_offset, usize::from(o.message_data_size)))
+                        .zip(slice(o.signature_instruction_index, o.signature_offset, 64));
+                    let actual = ix.sig(instructions, i);
+                    match (actual, expected) {
+                        (None, None) => (),
+                        (Some(a), Some(((pubkey, msg), sig))) => {
+                            prop_assert_eq!(a.pubkey.as_slice(), pubkey);
+                            prop_assert_eq!(a.msg, msg);
+                            prop_assert_eq!(a.sig.as_slice(), sig);
+                        }
+                        (a, e) => prop_assert!(false, "{a:?} {e:?}"),
+                    }
+                }
+                Ok(())
+            }
+
+            if is_r1 {
+                check(Secp256r1Ix::try_from_intro_instr(&intro_ix), &instructions, &ix_datas, precompile_idx, claimed_num_sigs, &offsets)?;
+                prop_assert!(Ed25519Ix::try_from_intro_instr(&intro_ix).is_none());
+            } else {
+                check(Ed25519Ix::try_from_intro_instr(&intro_ix), &instructions, &ix_datas, precompile_idx, claimed_num_sigs, &offsets)?;
+                prop_assert!(Secp256r1Ix::try_from_intro_instr(&intro_ix).is_none());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn secp256r1_self_referencing(
+            msg in vec(any::<u8>(), 0..512),
+            sig: [u8; 64],
+            pubkey: [u8; 33],
+        ) {
+            let public_key_offset = 16;
+            let signature_offset = public_key_offset + 33;
+            let message_data_offset = signature_offset + 64;
+            let mut ix_data = sig_verify_offsets_data(1, &[Ed25519SignatureOffsets {
+                signature_offset,
+                signature_instruction_index: u16::MAX,
+                public_key_offset,
+                public_key_instruction_index: u16::MAX,
+                message_data_offset,
+                message_data_size: msg.len() as u16,
+                message_instruction_index: u16::MAX,
+            }]);
+            ix_data.extend_from_slice(&pubkey);
+            ix_data.extend_from_slice(&sig);
+            ix_data.extend_from_slice(&msg);
+            let data = sol_instructions_data(&[Instruction {
+                program_id: solana_sdk_ids::secp256r1_program::ID,
+                accounts: vec![],
+                data: ix_data,
+            }]);
+            let instructions = Instructions { acc_data: &data };
+
+            let ix = Secp256r1Ix::try_from_intro_instr(&instructions.get(0).unwrap()).unwrap();
+            prop_assert_eq!(
+                ix.sig(&instructions, 0),
+                Some(Secp256r1Sig { pubkey: &pubkey, msg: &msg, sig: &sig })
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn secp256k1_against_sol(
+            after in vec(any_ix(), 0..3),
+            msg in vec(any::<u8>(), 0..512),
+            sig: [u8; 64],
+            recovery_id: u8,
+            eth_address: [u8; 20],
+        ) {
+            // sol's helper always uses instruction index 0.
+            // solana-secp256k1-program uses a different major version of solana-instruction
+            let sol_ix = new_secp256k1_instruction_with_signature(&msg, &sig, recovery_id, &eth_address);
+            let ixs: Vec<_> = [Instruction {
+                program_id: Pubkey::new_from_array(sol_ix.program_id.to_bytes()),
+                accounts: vec![],
+                data: sol_ix.data,
+            }]
+            .into_iter()
+            .chain(after)
+            .collect();
+            let data = sol_instructions_data(&ixs);
+            let instructions = Instructions { acc_data: &data };
+
+            for (i, ix) in instructions.iter().enumerate() {
+                prop_assert_eq!(Secp256k1Ix::try_from_intro_instr(&ix).is_some(), i == 0);
+            }
+
+            let ix = Secp256k1Ix::try_from_intro_instr(&instructions.get(0).unwrap()).unwrap();
+            let mut sig_recid = [0u8; 65];
+            sig_recid[..64].copy_from_slice(&sig);
+            sig_recid[64] = recovery_id;
+
+            prop_assert_eq!(ix.num_sigs(), 1);
+            prop_assert_eq!(
+                ix.sig(&instructions, 0),
+                Some(Secp256k1Sig { pubkey: &eth_address, msg: &msg, sig: &sig_recid })
+            );
+            prop_assert_eq!(ix.sig(&instructions, 1), None);
+            prop_assert_eq!(ix.sigs(instructions).collect::<Vec<_>>(), vec![ix.sig(&instructions, 0)]);
+        }
+    }
+
+    #[test]
+    fn too_short_data() {
+        for data in [&[][..], &[1], &[1; 11], &[2; 22]] {
+            assert!(Secp256k1Ix::try_from_data(data).is_none());
+        }
+        assert!(Secp256k1Ix::try_from_data(&[1; 12]).is_some());
+
+        for data in [&[][..], &[1], &[1; 15], &[2; 29]] {
+            assert!(Ed25519Ix::try_from_data(data).is_none());
+        }
+        assert!(Ed25519Ix::try_from_data(&[1; 16]).is_some());
+    }
+}
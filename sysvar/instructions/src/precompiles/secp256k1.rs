@@ -0,0 +1,191 @@
+use crate::{
+    precompiles::{data_array, data_slice, u16_le_at, PrecompileSig},
+    Instructions, IntroInstr,
+};
+
+pub const SECP256K1_PROGRAM_ID_STR: &str = "KeccakSecp256k11111111111111111111111111111";
+
+pub const SECP256K1_PROGRAM_ID: [u8; 32] =
+    const_crypto::bs58::decode_pubkey(SECP256K1_PROGRAM_ID_STR);
+
+/// Length of the ethereum address that the secp256k1 precompile verifies signatures against
+/// in place of a public key
+pub const SECP256K1_ETH_ADDR_LEN: usize = 20;
+
+/// 64-byte signature followed by 1-byte recovery ID
+pub const SECP256K1_SIG_LEN: usize = 65;
+
+pub const SECP256K1_SIG_OFFSETS_LEN: usize = 11;
+
+/// [`PrecompileSig::pubkey`] is the ethereum address,
+/// [`PrecompileSig::sig`] is the signature followed by the recovery ID
+pub type Secp256k1Sig<'a> = PrecompileSig<'a, SECP256K1_ETH_ADDR_LEN, SECP256K1_SIG_LEN>;
+
+/// A secp256k1 precompile instruction, with instruction data format:
+/// - u8 number of signatures
+/// - [`Secp256k1SigOffsets`] for each signature
+/// - arbitrary data that the offsets may point to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Secp256k1Ix<'a> {
+    /// Guaranteed to be long enough to contain all signature offsets
+    data: &'a [u8],
+}
+
+/// Constructors
+impl<'a> Secp256k1Ix<'a> {
+    const OFFSETS_START: usize = 1;
+
+    /// Returns `None` if `ix` is not a well-formed secp256k1 precompile instruction
+    #[inline]
+    pub fn try_from_intro_instr(ix: &IntroInstr<'a>) -> Option<Self> {
+        if *ix.program_id() == SECP256K1_PROGRAM_ID {
+            Self::try_from_data(ix.data())
+        } else {
+            None
+        }
+    }
+
+    /// Returns `None` if `data` is too short to contain all its signature offsets
+    #[inline]
+    pub(crate) const fn try_from_data(data: &'a [u8]) -> Option<Self> {
+        let num_sigs = match data.first() {
+            Some(n) => *n as usize,
+            None => return None,
+        };
+        if data.len() < Self::OFFSETS_START + num_sigs * SECP256K1_SIG_OFFSETS_LEN {
+            None
+        } else {
+            Some(Self { data })
+        }
+    }
+}
+
+/// Accessors
+impl<'a> Secp256k1Ix<'a> {
+    #[inline]
+    pub const fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    #[inline]
+    pub const fn num_sigs(&self) -> usize {
+        self.data[0] as usize
+    }
+
+    #[inline]
+    pub const fn offsets(&self) -> &'a [Secp256k1SigOffsets] {
+        // safety:
+        // - Secp256k1SigOffsets has no alignment requirements
+        // - length checked at construction
+        unsafe {
+            core::slice::from_raw_parts(
+                self.data.as_ptr().add(Self::OFFSETS_START).cast(),
+                self.num_sigs(),
+            )
+        }
+    }
+}
+
+/// Signature resolution
+impl<'a> Secp256k1Ix<'a> {
+    /// Returns the `idx`th signature verified by this instruction.
+    ///
+    /// `instructions` must be the instructions sysvar this instruction was introspected from.
+    ///
+    /// Returns `None` if `idx` is out of bounds or any of the signature's offsets
+    /// point out of bounds.
+    #[inline]
+    pub fn sig(&self, instructions: &Instructions<'a>, idx: usize) -> Option<Secp256k1Sig<'a>> {
+        self.offsets()
+            .get(idx)
+            .and_then(|offsets| Self::resolve(instructions, offsets))
+    }
+
+    /// Iterator over all signatures verified by this instruction,
+    /// each resolved as in [`Self::sig`]
+    #[inline]
+    pub fn sigs(
+        &self,
+        instructions: Instructions<'a>,
+    ) -> impl Iterator<Item = Option<Secp256k1Sig<'a>>> + 'a {
+        self.offsets()
+            .iter()
+            .map(move |offsets| Self::resolve(&instructions, offsets))
+    }
+
+    /// Resolves `offsets` against `instructions`.
+    ///
+    /// Unlike ed25519 and secp256r1, secp256k1 instruction indices are always
+    /// absolute indices into the transaction's instructions.
+    ///
+    /// Returns `None` if any offset points out of bounds.
+    #[inline]
+    pub fn resolve(
+        instructions: &Instructions<'a>,
+        offsets: &Secp256k1SigOffsets,
+    ) -> Option<Secp256k1Sig<'a>> {
+        let ix_data = |ix_idx: u8| instructions.get(usize::from(ix_idx)).map(|ix| ix.data());
+        Some(PrecompileSig {
+            pubkey: data_array(
+                ix_data(offsets.eth_address_instruction_index())?,
+                offsets.eth_address_offset(),
+            )?,
+            msg: data_slice(
+                ix_data(offsets.message_instruction_index())?,
+                offsets.message_data_offset(),
+                offsets.message_data_size(),
+            )?,
+            sig: data_array(
+                ix_data(offsets.signature_instruction_index())?,
+                offsets.signature_offset(),
+            )?,
+        })
+    }
+}
+
+/// Offsets of a single signature's data in a secp256k1 precompile instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Secp256k1SigOffsets([u8; SECP256K1_SIG_OFFSETS_LEN]);
+
+impl Secp256k1SigOffsets {
+    #[inline]
+    pub const fn as_buf(&self) -> &[u8; SECP256K1_SIG_OFFSETS_LEN] {
+        &self.0
+    }
+
+    #[inline]
+    pub const fn signature_offset(&self) -> u16 {
+        u16_le_at(&self.0, 0)
+    }
+
+    #[inline]
+    pub const fn signature_instruction_index(&self) -> u8 {
+        self.0[2]
+    }
+
+    #[inline]
+    pub const fn eth_address_offset(&self) -> u16 {
+        u16_le_at(&self.0, 3)
+    }
+
+    #[inline]
+    pub const fn eth_address_instruction_index(&self) -> u8 {
+        self.0[5]
+    }
+
+    #[inline]
+    pub const fn message_data_offset(&self) -> u16 {
+        u16_le_at(&self.0, 6)
+    }
+
+    #[inline]
+    pub const fn message_data_size(&self) -> u16 {
+        u16_le_at(&self.0, 8)
+    }
+
+    #[inline]
+    pub const fn message_instruction_index(&self) -> u8 {
+        self.0[10]
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{
+    precompiles::{PrecompileSig, SigVerifyIx, SIG_VERIFY_SIG_LEN},
+    IntroInstr,
+};
+
+pub const SECP256R1_PROGRAM_ID_STR: &str = "Secp256r1SigVerify1111111111111111111111111";
+
+pub const SECP256R1_PROGRAM_ID: [u8; 32] =
+    const_crypto::bs58::decode_pubkey(SECP256R1_PROGRAM_ID_STR);
+
+/// Compressed public key length
+pub const SECP256R1_PUBKEY_LEN: usize = 33;
+
+pub type Secp256r1Ix<'a> = SigVerifyIx<'a, SECP256R1_PUBKEY_LEN>;
+
+pub type Secp256r1Sig<'a> = PrecompileSig<'a, SECP256R1_PUBKEY_LEN, SIG_VERIFY_SIG_LEN>;
+
+impl<'a> Secp256r1Ix<'a> {
+    /// Returns `None` if `ix` is not a well-formed secp256r1 precompile instruction
+    #[inline]
+    pub fn try_from_intro_instr(ix: &IntroInstr<'a>) -> Option<Self> {
+        if *ix.program_id() == SECP256R1_PROGRAM_ID {
+            Self::try_from_data(ix.data())
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{
+    precompiles::{data_array, data_slice, u16_le_at, PrecompileSig},
+    Instructions,
+};
+
+pub const SIG_VERIFY_SIG_LEN: usize = 64;
+
+pub const SIG_VERIFY_SIG_OFFSETS_LEN: usize = 14;
+
+/// An ed25519 or secp256r1 precompile instruction.
+///
+/// Both precompiles share the same instruction data format,
+/// differing only in public key length:
+/// - u8 number of signatures
+/// - u8 padding
+/// - [`SigVerifySigOffsets`] for each signature
+/// - arbitrary data that the offsets may point to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SigVerifyIx<'a, const PK_LEN: usize> {
+    /// Guaranteed to be long enough to contain all signature offsets
+    data: &'a [u8],
+}
+
+/// Constructors
+impl<'a, const PK_LEN: usize> SigVerifyIx<'a, PK_LEN> {
+    const OFFSETS_START: usize = 2;
+
+    /// Returns `None` if `data` is too short to contain all its signature offsets
+    #[inline]
+    pub(crate) const fn try_from_data(data: &'a [u8]) -> Option<Self> {
+        let num_sigs = match data.first() {
+            Some(n) => *n as usize,
+            None => return None,
+        };
+        if data.len() < Self::OFFSETS_START + num_sigs * SIG_VERIFY_SIG_OFFSETS_LEN {
+            None
+        } else {
+            Some(Self { data })
+        }
+    }
+}
+
+/// Accessors
+impl<'a, const PK_LEN: usize> SigVerifyIx<'a, PK_LEN> {
+    #[inline]
+    pub const fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    #[inline]
+    pub const fn num_sigs(&self) -> usize {
+        self.data[0] as usize
+    }
+
+    #[inline]
+    pub const fn offsets(&self) -> &'a [SigVerifySigOffsets] {
+        // safety:
+        // - SigVerifySigOffsets has no alignment requirements
+        // - length checked at construction
+        unsafe {
+            core::slice::from_raw_parts(
+                self.data.as_ptr().add(Self::OFFSETS_START).cast(),
+                self.num_sigs(),
+            )
+        }
+    }
+}
+
+/// Signature resolution
+impl<'a, const PK_LEN: usize> SigVerifyIx<'a, PK_LEN> {
+    /// Returns the `idx`th signature verified by this instruction.
+    ///
+    /// `instructions` must be the instructions sysvar this instruction was introspected from.
+    ///
+    /// Returns `None` if `idx` is out of bounds or any of the signature's offsets
+    /// point out of bounds.
+    #[inline]
+    pub fn sig(
+        &self,
+        instructions: &Instructions<'a>,
+        idx: usize,
+    ) -> Option<PrecompileSig<'a, PK_LEN, SIG_VERIFY_SIG_LEN>> {
+        self.offsets()
+            .get(idx)
+            .and_then(|offsets| self.resolve(instructions, offsets))
+    }
+
+    /// Iterator over all signatures verified by this instruction,
+    /// each resolved as in [`Self::sig`]
+    #[inline]
+    pub fn sigs(
+        &self,
+        instructions: Instructions<'a>,
+    ) -> impl Iterator<Item = Option<PrecompileSig<'a, PK_LEN, SIG_VERIFY_SIG_LEN>>> + 'a {
+        let this = *self;
+        self.offsets()
+            .iter()
+            .map(move |offsets| this.resolve(&instructions, offsets))
+    }
+
+    /// Resolves `offsets` against `instructions`, or against this instruction's data
+    /// if the instruction index is [`u16::MAX`].
+    ///
+    /// Returns `None` if any offset points out of bounds.
+    #[inline]
+    pub fn resolve(
+        &self,
+        instructions: &Instructions<'a>,
+        offsets: &SigVerifySigOffsets,
+    ) -> Option<PrecompileSig<'a, PK_LEN, SIG_VERIFY_SIG_LEN>> {
+        let ix_data = |ix_idx: u16| {
+            if ix_idx == u16::MAX {
+                Some(self.data)
+            } else {
+                instructions.get(usize::from(ix_idx)).map(|ix| ix.data())
+            }
+        };
+        Some(PrecompileSig {
+            pubkey: data_array(
+                ix_data(offsets.public_key_instruction_index())?,
+                offsets.public_key_offset(),
+            )?,
+            msg: data_slice(
+                ix_data(offsets.message_instruction_index())?,
+                offsets.message_data_offset(),
+                offsets.message_data_size(),
+            )?,
+            sig: data_array(
+                ix_data(offsets.signature_instruction_index())?,
+                offsets.signature_offset(),
+            )?,
+        })
+    }
+}
+
+/// Offsets of a single signature's data in an ed25519 or secp256r1 precompile instruction.
+///
+/// An instruction index of [`u16::MAX`] refers to the precompile instruction itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SigVerifySigOffsets([u8; SIG_VERIFY_SIG_OFFSETS_LEN]);
+
+impl SigVerifySigOffsets {
+    #[inline]
+    pub const fn as_buf(&self) -> &[u8; SIG_VERIFY_SIG_OFFSETS_LEN] {
+        &self.0
+    }
+
+    #[inline]
+    pub const fn signature_offset(&self) -> u16 {
+        u16_le_at(&self.0, 0)
+    }
+
+    #[inline]
+    pub const fn signature_instruction_index(&self) -> u16 {
+        u16_le_at(&self.0, 2)
+    }
+
+    #[inline]
+    pub const fn public_key_offset(&self) -> u16 {
+        u16_le_at(&self.0, 4)
+    }
+
+    #[inline]
+    pub const fn public_key_instruction_index(&self) -> u16 {
+        u16_le_at(&self.0, 6)
+    }
+
+    #[inline]
+    pub const fn message_data_offset(&self) -> u16 {
+        u16_le_at(&self.0, 8)
+    }
+
+    #[inline]
+    pub const fn message_data_size(&self) -> u16 {
+        u16_le_at(&self.0, 10)
+    }
+
+    #[inline]
+    pub const fn message_instruction_index(&self) -> u16 {
+        u16_le_at(&self.0, 12)
+    }
+}
@@ -0,0 +1,215 @@
+//! Integer-only emulation of the float arithmetic in agave's `Rent::minimum_balance`:
+//!
+//! ```ignore
+//! ((bytes * lamports_per_byte_year) as f64 * exemption_threshold) as u64
+//! ```
+//!
+//! The sbf target has no hardware floats, so each of the 3 float ops above
+//! (`u64 as f64`, `f64 * f64`, `f64 as u64`) would otherwise pull in its own soft-float routine.
+//!
+//! Both conversions and the multiplication are emulated exactly as IEEE-754 specifies,
+//! round-half-to-even on `u64 as f64` and `f64 * f64`, truncation + saturation on `f64 as u64`,
+//! so that results match agave's bit-for-bit.
+
+const F64_MANT_BITS: u32 = 52;
+
+const F64_EXP_BITS: u32 = 11;
+
+const F64_EXP_MASK: u64 = (1 << F64_EXP_BITS) - 1;
+
+const F64_MANT_MASK: u64 = (1 << F64_MANT_BITS) - 1;
+
+/// Exponent bias + number of mantissa bits, so that a normal f64 with
+/// biased exponent `e` and mantissa `m` (including the implicit bit) has value
+/// `m * 2^(e - F64_EXP_OFFSET)`
+const F64_EXP_OFFSET: i32 = 1023 + F64_MANT_BITS as i32;
+
+/// Number of significant bits of a f64, including the implicit bit
+const F64_PRECISION: u32 = F64_MANT_BITS + 1;
+
+/// A finite non-negative float `mant * 2^exp`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Unpacked {
+    mant: u128,
+    exp: i32,
+}
+
+/// Rounds `mant * 2^exp` to [`F64_PRECISION`] significant bits, round-half-to-even.
+///
+/// Does not care about subnormals or overflow to infinity:
+/// - results in the subnormal range are < 1 and will be truncated to 0 by [`to_u64_sat`]
+///   no matter how they were rounded
+/// - results that overflow to infinity are >= 2^64 and will be saturated to [`u64::MAX`]
+///   by [`to_u64_sat`] all the same
+const fn round_to_precision(Unpacked { mant, exp }: Unpacked) -> Unpacked {
+    let bits = u128::BITS - mant.leading_zeros();
+    if bits <= F64_PRECISION {
+        return Unpacked { mant, exp };
+    }
+    let shift = bits - F64_PRECISION;
+    let mut res = mant >> shift;
+    let rem = mant & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if rem > half || (rem == half && res & 1 == 1) {
+        res += 1;
+    }
+    let mut exp = exp + shift as i32;
+    // rounding up carried into a new bit
+    if res >> F64_PRECISION != 0 {
+        res >>= 1;
+        exp += 1;
+    }
+    Unpacked { mant: res, exp }
+}
+
+/// `f64 as u64`: truncates towards zero, saturates at [`u64::MAX`]
+const fn to_u64_sat(Unpacked { mant, exp }: Unpacked) -> u64 {
+    if mant == 0 {
+        return 0;
+    }
+    if exp >= 0 {
+        let bits = u128::BITS - mant.leading_zeros();
+        // exp is bounded by f64 range, so this addition should never overflow
+        if bits as i32 + exp > u64::BITS as i32 {
+            u64::MAX
+        } else {
+            (mant << exp) as u64
+        }
+    } else {
+        // mant < 2^(F64_PRECISION + 1), so any shift of that or more is 0
+        let shift = -exp as u32;
+        if shift >= u128::BITS {
+            0
+        } else {
+            (mant >> shift) as u64
+        }
+    }
+}
+
+/// `(x as f64 * y) as u64`, without any float operations
+pub(crate) const fn u64_mul_f64_as_u64(x: u64, y: f64) -> u64 {
+    let bits = y.to_bits();
+    let is_neg = bits >> 63 != 0;
+    let biased_exp = (bits >> F64_MANT_BITS) & F64_EXP_MASK;
+    let frac = bits & F64_MANT_MASK;
+
+    // NaN * x = NaN and inf * 0 = NaN, both of which cast to 0.
+    // inf * positive x = inf, which saturates to u64::MAX
+    if biased_exp == F64_EXP_MASK {
+        return if frac != 0 || is_neg || x == 0 {
+            0
+        } else {
+            u64::MAX
+        };
+    }
+
+    // x as f64 is always >= 0.0, so product is either -0.0, 0.0, or negative,
+    // all of which cast to 0
+    if is_neg || x == 0 {
+        return 0;
+    }
+
+    let y = if biased_exp == 0 {
+        // subnormal
+        Unpacked {
+            mant: frac as u128,
+            exp: 1 - F64_EXP_OFFSET,
+        }
+    } else {
+        Unpacked {
+            mant: (frac | (1 << F64_MANT_BITS)) as u128,
+            exp: biased_exp as i32 - F64_EXP_OFFSET,
+        }
+    };
+
+    // `x as f64`
+    let x = round_to_precision(Unpacked {
+        mant: x as u128,
+        exp: 0,
+    });
+
+    // exact product of 2 <= 53-bit mantissas fits in 106 bits,
+    // then round once, same as the hardware would
+    let prod = round_to_precision(Unpacked {
+        mant: x.mant * y.mant,
+        exp: x.exp + y.exp,
+    });
+
+    to_u64_sat(prod)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn float_impl(x: u64, y: f64) -> u64 {
+        (x as f64 * y) as u64
+    }
+
+    const SPECIAL_F64S: [f64; 12] = [
+        0.0,
+        -0.0,
+        1.0,
+        2.0,
+        0.5,
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        f64::EPSILON,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+        // smallest subnormal
+        5e-324,
+    ];
+
+    const SPECIAL_U64S: [u64; 8] = [
+        0,
+        1,
+        2,
+        (1 << 53) - 1,
+        1 << 53,
+        (1 << 53) + 1,
+        u64::MAX - 1,
+        u64::MAX,
+    ];
+
+    #[test]
+    fn special_values_eq_float() {
+        for x in SPECIAL_U64S {
+            for y in SPECIAL_F64S {
+                assert_eq!(u64_mul_f64_as_u64(x, y), float_impl(x, y), "{x} * {y}");
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn any_bits_eq_float(x: u64, y_bits: u64) {
+            let y = f64::from_bits(y_bits);
+            prop_assert_eq!(u64_mul_f64_as_u64(x, y), float_impl(x, y));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn realistic_thresholds_eq_float(x: u64, y in 0.0..=255.0f64) {
+            prop_assert_eq!(u64_mul_f64_as_u64(x, y), float_impl(x, y));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn rounding_boundaries_eq_float(
+            // exercise round-half-to-even on both the int -> float conversion
+            // and the multiplication
+            x in (1u64 << 53)..,
+            mant in 0u64..(1 << F64_MANT_BITS),
+            exp in 1000u64..1100,
+        ) {
+            let y = f64::from_bits((exp << F64_MANT_BITS) | mant);
+            prop_assert_eq!(u64_mul_f64_as_u64(x, y), float_impl(x, y));
+        }
+    }
+}
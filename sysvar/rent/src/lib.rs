@@ -12,6 +12,8 @@ pub mod sysvar {
 }
 use sysvar::*;
 
+mod float;
+
 pub const ID_STR: &str = "SysvarRent111111111111111111111111111111111";
 
 pub const ID: [u8; 32] = const_crypto::bs58::decode_pubkey(ID_STR);
@@ -156,12 +158,13 @@ impl Rent {
     pub const fn min_balance_u64(&self, data_len: u64) -> u64 {
         // NB: this looks like overflow paradise but this is what the agave
         // implementation is like
+        let bytes_rent = (ACCOUNT_STORAGE_OVERHEAD + data_len) * self.lamports_per_byte_year;
         if self.is_default_rent_threshold() {
-            ((ACCOUNT_STORAGE_OVERHEAD + data_len) * self.lamports_per_byte_year)
-                * DEFAULT_EXEMPTION_THRESHOLD_AS_U64
+            bytes_rent * DEFAULT_EXEMPTION_THRESHOLD_AS_U64
         } else {
-            (((ACCOUNT_STORAGE_OVERHEAD + data_len) * self.lamports_per_byte_year) as f64
-                * self.exemption_threshold) as u64
+            // agave does `(bytes_rent as f64 * self.exemption_threshold) as u64`,
+            // emulate that with integer ops only to avoid pulling in soft-float routines
+            float::u64_mul_f64_as_u64(bytes_rent, self.exemption_threshold)
         }
     }
 
@@ -223,6 +226,21 @@ mod tests {
         }
     }
 
+    proptest! {
+        #[test]
+        fn check_any_threshold_bits_against_solana(
+            lamports_per_byte_year in 0u64..=u32::MAX as u64,
+            exemption_threshold_bits: u64,
+            data_len in 0..=u32::MAX as usize
+        ) {
+            let exemption_threshold = f64::from_bits(exemption_threshold_bits);
+            let sr = SolanaRent { lamports_per_byte_year, exemption_threshold, burn_percent: DEFAULT_BURN_PERCENT };
+            let r = Rent { lamports_per_byte_year, exemption_threshold, burn_percent: DEFAULT_BURN_PERCENT };
+
+            prop_assert_eq!(sr.minimum_balance(data_len), r.min_balance(data_len));
+        }
+    }
+
     proptest! {
         #[test]
         fn serde_roundtrip(
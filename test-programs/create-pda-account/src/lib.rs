@@ -0,0 +1,78 @@
+//! This program creates the rent-exempt PDA `[PDA_SEED, bump]` of this program,
+//! the second input account, with the u64 size specified by ix data, owned by this program,
+//! funded by the first input account.
+//!
+//! The PDA may already have lamports.
+//!
+//! The third input account should be the system program.
+
+#![allow(unexpected_cfgs)]
+
+use core::mem::MaybeUninit;
+
+use jiminy_cpi::account::{Abr, AccountHandle};
+use jiminy_entrypoint::program_error::{BuiltInProgramError, ProgramError};
+use jiminy_pda::{try_find_program_address_to, PdaSeed, PdaSigner};
+use jiminy_system_prog_interface::create_pda_account_idempotent;
+use jiminy_sysvar_rent::{sysvar::SimpleSysvar, Rent};
+
+pub const MAX_ACCS: usize = 3;
+pub const MAX_CPI_ACCS: usize = 3;
+
+pub const PDA_SEED: &[u8] = b"pda";
+
+type Cpi = jiminy_cpi::Cpi<MAX_CPI_ACCS>;
+
+jiminy_entrypoint::entrypoint!(process_ix, MAX_ACCS);
+
+fn process_ix(
+    abr: &mut Abr,
+    accounts: &[AccountHandle<'_>],
+    data: &[u8],
+    prog_id: &[u8; 32],
+) -> Result<(), ProgramError> {
+    let Some([funding, pda, sys_prog]) = accounts.first_chunk().copied() else {
+        return Err(ProgramError::from_builtin(
+            BuiltInProgramError::NotEnoughAccountKeys,
+        ));
+    };
+    let Some(space) = data.first_chunk().map(|s| u64::from_le_bytes(*s)) else {
+        return Err(ProgramError::from_builtin(
+            BuiltInProgramError::InvalidInstructionData,
+        ));
+    };
+
+    let mut pda_computed = MaybeUninit::uninit();
+    let mut bump = MaybeUninit::uninit();
+    let Some((pda_computed, bump)) = try_find_program_address_to(
+        &[PdaSeed::new(PDA_SEED)],
+        prog_id,
+        &mut pda_computed,
+        &mut bump,
+    ) else {
+        return Err(ProgramError::from_builtin(
+            BuiltInProgramError::InvalidSeeds,
+        ));
+    };
+    if pda_computed != abr.get(pda).key() {
+        return Err(ProgramError::from_builtin(
+            BuiltInProgramError::InvalidSeeds,
+        ));
+    }
+
+    let rent = Rent::get()?;
+    create_pda_account_idempotent(
+        &mut Cpi::new(),
+        abr,
+        sys_prog,
+        funding,
+        pda,
+        space as usize,
+        prog_id,
+        &rent,
+        &[PdaSigner::new(&[
+            PdaSeed::new(PDA_SEED),
+            PdaSeed::new(core::slice::from_ref(bump)),
+        ])],
+    )
+}
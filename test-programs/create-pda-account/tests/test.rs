@@ -0,0 +1,220 @@
+#![cfg(feature = "test-sbf")]
+
+use create_pda_account::PDA_SEED;
+use jiminy_test_utils::{bench_binsize, expect_test::expect, silence_mollusk_prog_logs};
+use mollusk_svm::{
+    program::keyed_account_for_system_program,
+    result::{Check, InstructionResult},
+    Mollusk,
+};
+use proptest::prelude::*;
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use solana_rent::Rent;
+
+const PROG_NAME: &str = "create_pda_account";
+const PROG_ID: Pubkey = solana_pubkey::pubkey!("4biKBM7JyLaNYQEpmyKmGpiDViuTeeHjyWFTyZxvRqmY");
+
+const FUNDING: Pubkey = solana_pubkey::pubkey!("7A87rRA9qxBzRaJr7a8dHcmsPW3QfbnH63SjFzZSoz4Q");
+
+thread_local! {
+    static SVM: Mollusk = Mollusk::new(&PROG_ID, PROG_NAME);
+}
+
+const FUNDING_IDX: usize = 0;
+const PDA_IDX: usize = 1;
+
+const FUNDING_STARTING_LAMPORTS: u64 = 1_000_000_000_000;
+
+#[test]
+fn binsize_bench() {
+    bench_binsize(PROG_NAME, expect![""]);
+}
+
+fn pda() -> Pubkey {
+    Pubkey::find_program_address(&[PDA_SEED], &PROG_ID).0
+}
+
+fn setup(space: usize, pda_account: Account) -> (Instruction, [(Pubkey, Account); 3]) {
+    let pda = pda();
+    (
+        Instruction::new_with_bytes(
+            PROG_ID,
+            &(space as u64).to_le_bytes(),
+            vec![
+                AccountMeta {
+                    pubkey: FUNDING,
+                    is_signer: true,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: pda,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: solana_system_program::id(),
+                    is_signer: false,
+                    is_writable: false,
+                },
+            ],
+        ),
+        [
+            (
+                FUNDING,
+                Account {
+                    lamports: FUNDING_STARTING_LAMPORTS,
+                    ..Default::default()
+                },
+            ),
+            (pda, pda_account),
+            keyed_account_for_system_program(),
+        ],
+    )
+}
+
+fn assert_created(space: usize, pda_account: Account) {
+    let pda_starting_lamports = pda_account.lamports;
+    let (ix, accounts) = setup(space, pda_account);
+
+    let InstructionResult {
+        raw_result,
+        resulting_accounts,
+        ..
+    } = SVM.with(|svm| {
+        svm.process_and_validate_instruction(&ix, &accounts, &[Check::all_rent_exempt()])
+    });
+
+    raw_result.unwrap();
+
+    let min_balance = Rent::default().minimum_balance(space);
+    let funding = &resulting_accounts[FUNDING_IDX].1;
+    let pda = &resulting_accounts[PDA_IDX].1;
+
+    assert_eq!(pda.owner, PROG_ID);
+    assert_eq!(pda.data.len(), space);
+    // only tops up shortfall, if any
+    assert_eq!(pda.lamports, min_balance.max(pda_starting_lamports));
+    // lamports are conserved between funding and pda
+    assert_eq!(
+        funding.lamports + pda.lamports,
+        FUNDING_STARTING_LAMPORTS + pda_starting_lamports
+    );
+}
+
+#[test]
+fn create_unfunded() {
+    assert_created(69, Account::default());
+}
+
+#[test]
+fn create_prefunded_below_rent_exempt() {
+    assert_created(
+        69,
+        Account {
+            lamports: 1,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn create_prefunded_above_rent_exempt() {
+    assert_created(
+        69,
+        Account {
+            lamports: Rent::default().minimum_balance(69) + 1,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn already_created_noop() {
+    const SPACE: usize = 69;
+    assert_created(
+        SPACE,
+        Account {
+            lamports: Rent::default().minimum_balance(SPACE),
+            data: vec![1; SPACE],
+            owner: PROG_ID,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn already_created_below_rent_exempt_topped_up() {
+    const SPACE: usize = 69;
+    assert_created(
+        SPACE,
+        Account {
+            lamports: Rent::default().minimum_balance(SPACE) - 1,
+            data: vec![1; SPACE],
+            owner: PROG_ID,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn already_owned_different_len_errs() {
+    const SPACE: usize = 69;
+    let (ix, accounts) = setup(
+        SPACE,
+        Account {
+            lamports: 1,
+            data: vec![1; SPACE + 1],
+            owner: PROG_ID,
+            ..Default::default()
+        },
+    );
+
+    let InstructionResult { raw_result, .. } =
+        SVM.with(|svm| svm.process_instruction(&ix, &accounts));
+
+    assert_eq!(
+        raw_result.unwrap_err(),
+        solana_instruction::error::InstructionError::AccountAlreadyInitialized
+    );
+}
+
+proptest! {
+    #[test]
+    fn create_pda_account_idempotent_rent_exempt(
+        space in 0usize..=1024 * 10,
+        prefund in 0u64..=2_000_000_000,
+    ) {
+        silence_mollusk_prog_logs();
+
+        assert_created(
+            space,
+            Account {
+                lamports: prefund,
+                ..Default::default()
+            },
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn already_created_rent_exempt(
+        space in 0usize..=1024 * 10,
+        // an account with data cannot have 0 lamports
+        lamports in 1u64..=2_000_000_000,
+    ) {
+        silence_mollusk_prog_logs();
+
+        assert_created(
+            space,
+            Account {
+                lamports,
+                data: vec![1; space],
+                owner: PROG_ID,
+                ..Default::default()
+            },
+        );
+    }
+}
@@ -0,0 +1,68 @@
+//! This program emits a `Transfer` event for its first 2 accounts
+//! with the amount in the first 8 bytes of instruction data,
+//! then a `Memo` event with the remaining instruction data.
+
+#![allow(unexpected_cfgs)]
+
+use jiminy_entrypoint::{
+    account::{Abr, AccountHandle},
+    program_error::{ProgramError, INVALID_INSTRUCTION_DATA, NOT_ENOUGH_ACCOUNT_KEYS},
+};
+use jiminy_log::{emit, event_discriminator, Event};
+
+pub const MAX_ACCS: usize = 2;
+
+/// Zero-copy event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct Transfer {
+    pub from: [u8; 32],
+    pub to: [u8; 32],
+    pub amount: [u8; 8],
+}
+
+impl Event for Transfer {
+    const DISCRIMINATOR: [u8; 8] = event_discriminator("Transfer");
+
+    #[inline]
+    fn body(&self) -> &[u8] {
+        // safety: repr(C) with only byte array fields, no padding
+        unsafe {
+            core::slice::from_raw_parts(
+                core::ptr::from_ref(self).cast(),
+                core::mem::size_of::<Self>(),
+            )
+        }
+    }
+}
+
+/// Discriminator of the borsh `Memo { data: Vec<u8> }` event
+pub const MEMO_DISCRIMINATOR: [u8; 8] = event_discriminator("Memo");
+
+jiminy_entrypoint::entrypoint!(process_ix, MAX_ACCS);
+
+fn process_ix(
+    abr: &mut Abr,
+    accounts: &[AccountHandle<'_>],
+    data: &[u8],
+    _prog_id: &[u8; 32],
+) -> Result<(), ProgramError> {
+    let [from, to] = match accounts {
+        [from, to] => [from, to].map(|h| *abr.get(*h).key()),
+        _ => return Err(NOT_ENOUGH_ACCOUNT_KEYS.into()),
+    };
+    let (amount, memo) = data
+        .split_first_chunk::<8>()
+        .ok_or(INVALID_INSTRUCTION_DATA)?;
+
+    emit(&Transfer {
+        from,
+        to,
+        amount: *amount,
+    });
+
+    // borsh Vec<u8>: u32 len prefix followed by the bytes
+    emit!(MEMO_DISCRIMINATOR; &(memo.len() as u32).to_le_bytes(), memo);
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "test-sbf")]
+
+use emit_event::{Transfer, MEMO_DISCRIMINATOR};
+use jiminy_log::EVENT_DISCRIMINATOR_LEN;
+use jiminy_test_utils::{
+    bench_binsize, decode_events, expect_test::expect, program_data, silence_mollusk_prog_logs,
+};
+use mollusk_svm::{result::InstructionResult, Mollusk};
+use proptest::prelude::*;
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use solana_svm_log_collector::LogCollector;
+
+const PROG_NAME: &str = "emit_event";
+const PROG_ID: Pubkey = solana_pubkey::pubkey!("Hr9wsgMm4A5A3eE7eobvSWzHBNrNixakzDfsmE4cQKqq");
+
+#[test]
+fn binsize_bench() {
+    bench_binsize(PROG_NAME, expect![""]);
+}
+
+/// Returns (logs, compute units consumed)
+fn emit_event_ix(from: [u8; 32], to: [u8; 32], data: &[u8]) -> (Vec<String>, u64) {
+    let mut svm = Mollusk::new(&PROG_ID, PROG_NAME);
+    let logger = LogCollector::new_ref();
+    svm.logger = Some(logger.clone());
+
+    let accs = [from, to].map(Pubkey::new_from_array);
+    let metas = accs.map(|pubkey| AccountMeta {
+        pubkey,
+        is_signer: false,
+        is_writable: false,
+    });
+    let ix = Instruction::new_with_bytes(PROG_ID, data, metas.to_vec());
+    let accounts = accs.map(|pubkey| (pubkey, Account::default()));
+
+    let InstructionResult {
+        compute_units_consumed,
+        raw_result,
+        ..
+    } = svm.process_instruction(&ix, &accounts);
+    raw_result.unwrap();
+
+    let logs = logger.borrow().get_recorded_content().to_vec();
+    // discriminator and body slices are logged as separate fields, not concatenated
+    logs.iter()
+        .filter_map(|l| l.strip_prefix("Program data: "))
+        .for_each(|fields| assert!(fields.split(' ').count() >= 2, "{fields}"));
+    (logs, compute_units_consumed)
+}
+
+fn decode_transfer(body: &[u8]) -> Transfer {
+    let (from, rest) = body.split_first_chunk().unwrap();
+    let (to, rest) = rest.split_first_chunk().unwrap();
+    let amount = rest.try_into().unwrap();
+    Transfer {
+        from: *from,
+        to: *to,
+        amount,
+    }
+}
+
+fn decode_memo(data: &[u8]) -> Option<Vec<u8>> {
+    let (discm, body) = data.split_first_chunk::<EVENT_DISCRIMINATOR_LEN>()?;
+    if *discm != MEMO_DISCRIMINATOR {
+        return None;
+    }
+    let (len, memo) = body.split_first_chunk::<4>()?;
+    assert_eq!(u32::from_le_bytes(*len) as usize, memo.len());
+    Some(memo.to_vec())
+}
+
+#[test]
+fn emit_event_basic_cus() {
+    let mut data = 1_000_000_000u64.to_le_bytes().to_vec();
+    data.extend_from_slice(b"hello");
+
+    let (logs, cus) = emit_event_ix([1; 32], [2; 32], &data);
+
+    assert_eq!(
+        decode_events(&logs, decode_transfer),
+        [Transfer {
+            from: [1; 32],
+            to: [2; 32],
+            amount: 1_000_000_000u64.to_le_bytes(),
+        }]
+    );
+    let memos: Vec<_> = program_data(&logs)
+        .iter()
+        .filter_map(|d| decode_memo(d))
+        .collect();
+    assert_eq!(memos, [b"hello".to_vec()]);
+
+    expect![""].assert_eq(&cus.to_string());
+}
+
+proptest! {
+    #[test]
+    fn emit_event_any(
+        from: [u8; 32],
+        to: [u8; 32],
+        amount: u64,
+        memo in proptest::collection::vec(any::<u8>(), 0..128),
+    ) {
+        silence_mollusk_prog_logs();
+
+        let data = [amount.to_le_bytes().as_slice(), &memo].concat();
+        let (logs, _) = emit_event_ix(from, to, &data);
+
+        prop_assert_eq!(
+            decode_events(&logs, decode_transfer),
+            [Transfer { from, to, amount: amount.to_le_bytes() }]
+        );
+        let memos: Vec<_> = program_data(&logs)
+            .iter()
+            .filter_map(|d| decode_memo(d))
+            .collect();
+        prop_assert_eq!(memos, [memo]);
+    }
+}
@@ -0,0 +1,43 @@
+//! This program prints a bunch of stuff with `log!`, without the `std` feature.
+//! Compare with log-hello-world, which uses `msg!`.
+
+#![allow(unexpected_cfgs)]
+
+use jiminy_entrypoint::{
+    account::{Abr, AccountHandle},
+    program_error::ProgramError,
+};
+use jiminy_log::log;
+
+pub const MAX_ACCS: usize = 128;
+
+jiminy_entrypoint::entrypoint!(process_ix, MAX_ACCS);
+
+fn process_ix(
+    abr: &mut Abr,
+    accounts: &[AccountHandle<'_>],
+    data: &[u8],
+    prog_id: &[u8; 32],
+) -> Result<(), ProgramError> {
+    log!("Hello jiminy!");
+
+    log!("program {}, {} accounts", prog_id, accounts.len());
+
+    accounts.iter().enumerate().for_each(|(i, h)| {
+        let acc = abr.get(*h);
+        log!(
+            "account {}: {} signer={} writable={}",
+            i,
+            acc.key(),
+            acc.is_signer(),
+            acc.is_writable(),
+        );
+    });
+
+    let signed = data
+        .first_chunk()
+        .map_or(0, |c: &[u8; 8]| i64::from_le_bytes(*c));
+    log!("data len {}, first i64 {}", data.len(), signed);
+
+    Ok(())
+}
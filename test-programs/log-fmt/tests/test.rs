@@ -0,0 +1,111 @@
+#![cfg(feature = "test-sbf")]
+
+use jiminy_test_utils::{bench_binsize, expect_test::expect, silence_mollusk_prog_logs};
+use mollusk_svm::{result::InstructionResult, Mollusk};
+use proptest::prelude::*;
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use solana_svm_log_collector::LogCollector;
+
+const PROG_NAME: &str = "log_fmt";
+const PROG_ID: Pubkey = solana_pubkey::pubkey!("Hr9wsgMm4A5A3eE7eobvSWzHBNrNixakzDfsmE4cQKqq");
+
+const PROG_LOG_PREFIX: &str = "Program log: ";
+
+const TEST_ACC_PK_1: Pubkey =
+    solana_pubkey::pubkey!("CkebHSWNvZ5w9Q3GTivrEomZZmwWFNqPpzVA9NFZxpg8");
+const TEST_ACC_PK_2: Pubkey =
+    solana_pubkey::pubkey!("FpaavSQvEQhPDoQoLUHhmBsKZsG2WJQXj7FBCSPE1TZ1");
+
+/// Compare against log-hello-world's binsize_bench, which uses `msg!`
+#[test]
+fn binsize_bench() {
+    bench_binsize(PROG_NAME, expect![""]);
+}
+
+/// Returns (`Program log: ` lines with the prefix stripped, compute units consumed)
+fn log_fmt_ix(accs: &[(Pubkey, bool)], data: &[u8]) -> (Vec<String>, u64) {
+    let mut svm = Mollusk::new(&PROG_ID, PROG_NAME);
+    let logger = LogCollector::new_ref();
+    svm.logger = Some(logger.clone());
+
+    let metas = accs
+        .iter()
+        .map(|(pubkey, is_writable)| AccountMeta {
+            pubkey: *pubkey,
+            is_signer: false,
+            is_writable: *is_writable,
+        })
+        .collect();
+    let ix = Instruction::new_with_bytes(PROG_ID, data, metas);
+    let accounts: Vec<_> = accs
+        .iter()
+        .map(|(pubkey, _)| (*pubkey, Account::default()))
+        .collect();
+
+    let InstructionResult {
+        compute_units_consumed,
+        raw_result,
+        ..
+    } = svm.process_instruction(&ix, &accounts);
+    raw_result.unwrap();
+
+    let logs = logger
+        .borrow()
+        .get_recorded_content()
+        .iter()
+        .filter_map(|l| l.strip_prefix(PROG_LOG_PREFIX))
+        .map(str::to_owned)
+        .collect();
+    (logs, compute_units_consumed)
+}
+
+/// What the program should log, formatted with `std`
+fn expected_logs(accs: &[(Pubkey, bool)], data: &[u8]) -> Vec<String> {
+    let first_i64 = data
+        .first_chunk()
+        .map_or(0, |c: &[u8; 8]| i64::from_le_bytes(*c));
+    ["Hello jiminy!".to_owned()]
+        .into_iter()
+        .chain([format!("program {PROG_ID}, {} accounts", accs.len())])
+        .chain(accs.iter().enumerate().map(|(i, (pubkey, is_writable))| {
+            format!("account {i}: {pubkey} signer=false writable={is_writable}")
+        }))
+        .chain([format!("data len {}, first i64 {first_i64}", data.len())])
+        .collect()
+}
+
+#[test]
+fn log_fmt_basic_cus() {
+    let accs = [(TEST_ACC_PK_1, false), (TEST_ACC_PK_2, false)];
+    let data = (-1i64).to_le_bytes();
+
+    let (logs, cus) = log_fmt_ix(&accs, &data);
+
+    assert_eq!(logs, expected_logs(&accs, &data));
+    expect![""].assert_eq(&cus.to_string());
+}
+
+proptest! {
+    #[test]
+    fn log_fmt_any(
+        accs in proptest::collection::vec((any::<[u8; 32]>(), any::<bool>()), 0..8),
+        data in proptest::collection::vec(any::<u8>(), 0..128),
+    ) {
+        silence_mollusk_prog_logs();
+
+        let accs: Vec<_> = accs
+            .into_iter()
+            .map(|(pubkey, is_writable)| (Pubkey::new_from_array(pubkey), is_writable))
+            .collect();
+        // duplicate accounts' writability is merged by the runtime
+        for (i, (pubkey, _)) in accs.iter().enumerate() {
+            prop_assume!(!accs[..i].iter().any(|(pk, _)| pk == pubkey));
+        }
+
+        let (logs, _) = log_fmt_ix(&accs, &data);
+
+        prop_assert_eq!(logs, expected_logs(&accs, &data));
+    }
+}
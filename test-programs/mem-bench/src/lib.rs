@@ -0,0 +1,99 @@
+//! This program runs a single mem op on a stack buffer, with either `core`'s impl
+//! or the mem op syscall, to measure the CU crossover points used by jiminy-mem.
+//!
+//! Instruction data: `[op: u8, impl: u8, len: u16 le]`
+//! - `op`: one of the `OP_*` consts
+//! - `impl`: one of the `IMPL_*` consts
+//! - `len`: number of bytes operated on, at most [`MAX_LEN`]
+
+#![allow(unexpected_cfgs)]
+
+use core::hint::black_box;
+
+use jiminy_entrypoint::{
+    account::{Abr, AccountHandle},
+    program_error::{BuiltInProgramError, ProgramError},
+};
+// called directly so that jiminy-mem's length threshold doesn't apply
+use jiminy_mem::sys;
+
+pub const MAX_ACCS: usize = 0;
+
+pub const MAX_LEN: usize = 1024;
+
+/// Does nothing but set up the buffers, to subtract from the other ops' CUs
+pub const OP_BASELINE: u8 = 0;
+pub const OP_COPY: u8 = 1;
+pub const OP_MOVE: u8 = 2;
+pub const OP_COMPARE: u8 = 3;
+pub const OP_FILL: u8 = 4;
+
+pub const IMPL_CORE: u8 = 0;
+pub const IMPL_SYSCALL: u8 = 1;
+
+jiminy_entrypoint::entrypoint!(process_ix, MAX_ACCS);
+
+fn process_ix(
+    _abr: &mut Abr,
+    _accounts: &[AccountHandle<'_>],
+    data: &[u8],
+    _prog_id: &[u8; 32],
+) -> Result<(), ProgramError> {
+    let Some([op, imp, l, h]) = data.first_chunk().copied() else {
+        return Err(ProgramError::from_builtin(
+            BuiltInProgramError::InvalidInstructionData,
+        ));
+    };
+    let len = usize::from(u16::from_le_bytes([l, h]));
+    if len > MAX_LEN {
+        return Err(ProgramError::from_builtin(
+            BuiltInProgramError::InvalidInstructionData,
+        ));
+    }
+
+    // + 1 so that move can shift by 1 byte
+    let mut a = black_box([1u8; MAX_LEN + 1]);
+    let b = black_box([1u8; MAX_LEN + 1]);
+    let is_syscall = imp == IMPL_SYSCALL;
+
+    match op {
+        OP_BASELINE => (),
+        OP_COPY => {
+            if is_syscall {
+                unsafe { sys::memcpy(a.as_mut_ptr(), b.as_ptr(), len) }
+            } else {
+                a[..len].copy_from_slice(&b[..len]);
+            }
+        }
+        OP_MOVE => {
+            if is_syscall {
+                unsafe { sys::memmove(a.as_mut_ptr().add(1), a.as_ptr(), len) }
+            } else {
+                a.copy_within(..len, 1);
+            }
+        }
+        OP_COMPARE => {
+            let ord = if is_syscall {
+                unsafe { sys::memcmp(a.as_ptr(), b.as_ptr(), len) }.cmp(&0)
+            } else {
+                a[..len].cmp(&b[..len])
+            };
+            black_box(ord);
+        }
+        OP_FILL => {
+            if is_syscall {
+                unsafe { sys::memset(a.as_mut_ptr(), 2, len) }
+            } else {
+                a[..len].fill(2);
+            }
+        }
+        _ => {
+            return Err(ProgramError::from_builtin(
+                BuiltInProgramError::InvalidInstructionData,
+            ))
+        }
+    }
+    black_box(&a);
+
+    Ok(())
+}
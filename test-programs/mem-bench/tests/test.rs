@@ -0,0 +1,103 @@
+#![cfg(feature = "test-sbf")]
+
+use jiminy_mem::SYSCALL_MIN_LEN;
+use jiminy_test_utils::{bench_binsize, expect_test::expect};
+use mem_bench::{IMPL_CORE, IMPL_SYSCALL, OP_BASELINE, OP_COMPARE, OP_COPY, OP_FILL, OP_MOVE};
+use mollusk_svm::{result::InstructionResult, Mollusk};
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+const PROG_NAME: &str = "mem_bench";
+const PROG_ID: Pubkey = solana_pubkey::pubkey!("3SHUvMPRxyYb4pJkHc5Zbgcy5jDUkpCqLvGsfAvzWPK9");
+
+thread_local! {
+    static SVM: Mollusk = Mollusk::new(&PROG_ID, PROG_NAME);
+}
+
+const OPS: [(&str, u8); 4] = [
+    ("copy", OP_COPY),
+    ("move", OP_MOVE),
+    ("compare", OP_COMPARE),
+    ("fill", OP_FILL),
+];
+
+const LENS: [usize; 14] = [0, 1, 2, 4, 8, 16, 24, 32, 48, 64, 128, 256, 512, 1024];
+
+#[test]
+fn binsize_bench() {
+    bench_binsize(PROG_NAME, expect![""]);
+}
+
+fn ix_cus(op: u8, imp: u8, len: usize) -> u64 {
+    let len = u16::try_from(len).unwrap().to_le_bytes();
+    let ix = Instruction::new_with_bytes(PROG_ID, &[op, imp, len[0], len[1]], vec![]);
+    let InstructionResult {
+        compute_units_consumed,
+        raw_result,
+        ..
+    } = SVM.with(|svm| svm.process_instruction(&ix, &[]));
+    raw_result.unwrap();
+    compute_units_consumed
+}
+
+/// CUs of the op alone, excluding ix parsing and buffer setup
+fn op_cus(op: u8, imp: u8, len: usize) -> u64 {
+    ix_cus(op, imp, len) - ix_cus(OP_BASELINE, IMPL_CORE, len)
+}
+
+/// Returns the smallest len in `LENS` from which the syscall
+/// costs no more than `core` for all larger lens in `LENS`
+fn crossover(op: u8) -> usize {
+    LENS.iter()
+        .rev()
+        .take_while(|len| op_cus(op, IMPL_SYSCALL, **len) <= op_cus(op, IMPL_CORE, **len))
+        .last()
+        .copied()
+        .unwrap_or(usize::MAX)
+}
+
+/// `{len}: {op} core/syscall ...` for every benched len and op
+#[test]
+fn mem_op_cus() {
+    let table: String = LENS
+        .iter()
+        .map(|len| {
+            let row: String = OPS
+                .iter()
+                .map(|(name, op)| {
+                    format!(
+                        " {name} {}/{}",
+                        op_cus(*op, IMPL_CORE, *len),
+                        op_cus(*op, IMPL_SYSCALL, *len)
+                    )
+                })
+                .collect();
+            format!("{len}:{row}\n")
+        })
+        .collect();
+    expect![""].assert_eq(&table);
+}
+
+#[test]
+fn crossovers() {
+    let crossovers: String = OPS
+        .iter()
+        .map(|(name, op)| format!("{name}: {}\n", crossover(*op)))
+        .collect();
+    expect![""].assert_eq(&crossovers);
+}
+
+/// jiminy-mem uses a single threshold for all ops,
+/// which must lie within the measured crossovers
+#[test]
+fn syscall_min_len_within_crossovers() {
+    let crossovers = OPS.map(|(_, op)| crossover(op));
+    let [min, max] = [
+        crossovers.iter().min().unwrap(),
+        crossovers.iter().max().unwrap(),
+    ];
+    assert!(
+        (*min..=*max).contains(&SYSCALL_MIN_LEN),
+        "{SYSCALL_MIN_LEN} not in {crossovers:?}"
+    );
+}
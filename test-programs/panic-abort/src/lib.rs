@@ -0,0 +1,30 @@
+//! This program always panics, to test `abort_panic_handler!`.
+//! Compare with panic-detailed, which panics the same way but logs the panic.
+//!
+//! - empty instruction data: panics with a static message
+//! - else: panics with a formatted message containing the instruction data
+
+#![allow(unexpected_cfgs)]
+
+use jiminy_entrypoint::{
+    account::{Abr, AccountHandle},
+    program_error::ProgramError,
+};
+
+pub const MAX_ACCS: usize = 0;
+
+jiminy_entrypoint::program_entrypoint!(process_ix, MAX_ACCS);
+jiminy_entrypoint::default_allocator!();
+jiminy_entrypoint::abort_panic_handler!();
+
+fn process_ix(
+    _abr: &mut Abr,
+    _accounts: &[AccountHandle<'_>],
+    data: &[u8],
+    _prog_id: &[u8; 32],
+) -> Result<(), ProgramError> {
+    if data.is_empty() {
+        panic!("static panic");
+    }
+    panic!("formatted panic: {data:?}");
+}
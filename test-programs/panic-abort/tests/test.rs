@@ -0,0 +1,50 @@
+#![cfg(feature = "test-sbf")]
+
+use jiminy_test_utils::{bench_binsize, expect_test::expect, silence_mollusk_prog_logs};
+use mollusk_svm::{result::InstructionResult, Mollusk};
+use proptest::prelude::*;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+use solana_svm_log_collector::LogCollector;
+
+const PROG_NAME: &str = "panic_abort";
+const PROG_ID: Pubkey = solana_pubkey::pubkey!("Hr9wsgMm4A5A3eE7eobvSWzHBNrNixakzDfsmE4cQKqq");
+
+const PROG_LOG_PREFIX: &str = "Program log: ";
+
+/// Compare against panic-detailed's binsize_bench
+#[test]
+fn binsize_bench() {
+    bench_binsize(PROG_NAME, expect![""]);
+}
+
+/// Asserts the ix fails without the program logging anything
+fn assert_panics_silently(data: &[u8]) {
+    let mut svm = Mollusk::new(&PROG_ID, PROG_NAME);
+    let logger = LogCollector::new_ref();
+    svm.logger = Some(logger.clone());
+
+    let ix = Instruction::new_with_bytes(PROG_ID, data, vec![]);
+    let InstructionResult { raw_result, .. } = svm.process_instruction(&ix, &[]);
+    assert!(raw_result.is_err());
+
+    let logs = logger.borrow().get_recorded_content().to_vec();
+    assert!(
+        !logs.iter().any(|l| l.starts_with(PROG_LOG_PREFIX)),
+        "{logs:#?}"
+    );
+}
+
+#[test]
+fn static_msg() {
+    assert_panics_silently(&[]);
+}
+
+proptest! {
+    #[test]
+    fn formatted_msg(data in proptest::collection::vec(any::<u8>(), 1..=32)) {
+        silence_mollusk_prog_logs();
+
+        assert_panics_silently(&data);
+    }
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "test-sbf")]
+
+use jiminy_test_utils::{bench_binsize, expect_test::expect, silence_mollusk_prog_logs};
+use mollusk_svm::{result::InstructionResult, Mollusk};
+use proptest::prelude::*;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+use solana_svm_log_collector::LogCollector;
+
+const PROG_NAME: &str = "panic_detailed";
+const PROG_ID: Pubkey = solana_pubkey::pubkey!("Hr9wsgMm4A5A3eE7eobvSWzHBNrNixakzDfsmE4cQKqq");
+
+const PROG_LOG_PREFIX: &str = "Program log: ";
+
+#[test]
+fn binsize_bench() {
+    bench_binsize(PROG_NAME, expect![""]);
+}
+
+/// Returns the message part of the panic log line
+/// after asserting it has the form `{file}:{line}:{col}: {msg}`
+fn panic_ix(data: &[u8]) -> String {
+    let mut svm = Mollusk::new(&PROG_ID, PROG_NAME);
+    let logger = LogCollector::new_ref();
+    svm.logger = Some(logger.clone());
+
+    let ix = Instruction::new_with_bytes(PROG_ID, data, vec![]);
+    let InstructionResult { raw_result, .. } = svm.process_instruction(&ix, &[]);
+    assert!(raw_result.is_err());
+
+    let logs = logger.borrow().get_recorded_content().to_vec();
+    let line = logs
+        .iter()
+        .find_map(|l| {
+            l.strip_prefix(PROG_LOG_PREFIX)
+                .filter(|l| l.contains("src/lib.rs:"))
+        })
+        .unwrap();
+    let (location, msg) = line.split_once(": ").unwrap();
+    let mut parts = location.rsplitn(3, ':');
+    let [col, ln] = [0; 2].map(|_| parts.next().unwrap().parse::<u32>().unwrap());
+    assert!(col > 0 && ln > 0);
+    assert!(parts.next().unwrap().ends_with("src/lib.rs"));
+
+    msg.to_owned()
+}
+
+#[test]
+fn static_msg() {
+    assert_eq!(panic_ix(&[]), "static panic");
+}
+
+proptest! {
+    #[test]
+    fn formatted_msg(data in proptest::collection::vec(any::<u8>(), 1..=32)) {
+        silence_mollusk_prog_logs();
+
+        prop_assert_eq!(panic_ix(&data), format!("formatted panic: {data:?}"));
+    }
+}
+
+proptest! {
+    #[test]
+    fn long_formatted_msg_truncated(data in proptest::collection::vec(any::<u8>(), 128..=512)) {
+        silence_mollusk_prog_logs();
+
+        let msg = panic_ix(&data);
+        let expected = format!("formatted panic: {data:?}");
+        prop_assert!(msg.len() < expected.len());
+        prop_assert!(expected.starts_with(&msg));
+    }
+}
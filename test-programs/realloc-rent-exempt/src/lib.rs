@@ -0,0 +1,37 @@
+//! This program reallocs the first input account, owned by this program,
+//! to the u64 size specified by ix data, keeping it rent-exempt
+//! by topping up from the second input account or refunding to the third input account.
+//!
+//! The fourth input account should be the system program.
+
+#![allow(unexpected_cfgs)]
+
+use jiminy_cpi::account::{Abr, AccountHandle};
+use jiminy_entrypoint::program_error::{BuiltInProgramError, ProgramError};
+use jiminy_system_prog_interface::ReallocRentExempt;
+use jiminy_sysvar_rent::{sysvar::SimpleSysvar, Rent};
+
+pub const MAX_ACCS: usize = 4;
+
+jiminy_entrypoint::entrypoint!(process_ix, MAX_ACCS);
+
+fn process_ix(
+    abr: &mut Abr,
+    accounts: &[AccountHandle<'_>],
+    data: &[u8],
+    _prog_id: &[u8; 32],
+) -> Result<(), ProgramError> {
+    let Some([acc, payer, refund_to, _sys_prog]) = accounts.first_chunk().copied() else {
+        return Err(ProgramError::from_builtin(
+            BuiltInProgramError::NotEnoughAccountKeys,
+        ));
+    };
+    let Some(new_len) = data.first_chunk().map(|s| u64::from_le_bytes(*s)) else {
+        return Err(ProgramError::from_builtin(
+            BuiltInProgramError::InvalidInstructionData,
+        ));
+    };
+
+    let rent = Rent::get()?;
+    abr.realloc_rent_exempt(acc, new_len as usize, payer, refund_to, &rent)
+}
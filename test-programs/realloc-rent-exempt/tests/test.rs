@@ -0,0 +1,199 @@
+#![cfg(feature = "test-sbf")]
+
+use jiminy_entrypoint::account::MAX_PERMITTED_DATA_INCREASE;
+use jiminy_test_utils::{
+    bench_binsize, expect_test::expect, silence_mollusk_prog_logs, two_different_pubkeys,
+};
+use mollusk_svm::{
+    program::keyed_account_for_system_program,
+    result::{Check, InstructionResult},
+    Mollusk,
+};
+use proptest::prelude::*;
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use solana_rent::Rent;
+
+const PROG_NAME: &str = "realloc_rent_exempt";
+const PROG_ID: Pubkey = solana_pubkey::pubkey!("FwvCDay3oC5Nq6RkNECEAJMA2ebKFAvqWYsj41TBHNQt");
+
+thread_local! {
+    static SVM: Mollusk = Mollusk::new(&PROG_ID, PROG_NAME);
+}
+
+const ACC_IDX: usize = 0;
+const PAYER_IDX: usize = 1;
+const REFUND_TO_IDX: usize = 2;
+
+const PAYER_STARTING_LAMPORTS: u64 = 1_000_000_000_000;
+
+const REFUND_TO: Pubkey = solana_pubkey::pubkey!("9diwgHx6xrDjrvXUVx8B4drJMzv9ddh9fBSx59EWjFPU");
+const REFUND_TO_STARTING_LAMPORTS: u64 = 1_000_000;
+
+#[test]
+fn binsize_bench() {
+    bench_binsize(PROG_NAME, expect![""]);
+}
+
+fn setup(
+    acc: Pubkey,
+    original_len: usize,
+    new_len: usize,
+    payer: Pubkey,
+    payer_owner: Pubkey,
+) -> (Instruction, [(Pubkey, Account); 4]) {
+    let rent = Rent::default();
+    (
+        Instruction::new_with_bytes(
+            PROG_ID,
+            &(new_len as u64).to_le_bytes(),
+            vec![
+                AccountMeta {
+                    pubkey: acc,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: payer,
+                    is_signer: true,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: REFUND_TO,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: solana_system_program::id(),
+                    is_signer: false,
+                    is_writable: false,
+                },
+            ],
+        ),
+        [
+            (
+                acc,
+                Account {
+                    lamports: rent.minimum_balance(original_len),
+                    data: vec![1; original_len],
+                    owner: PROG_ID,
+                    executable: false,
+                    rent_epoch: u64::MAX,
+                },
+            ),
+            (
+                payer,
+                Account {
+                    lamports: PAYER_STARTING_LAMPORTS,
+                    owner: payer_owner,
+                    ..Default::default()
+                },
+            ),
+            (
+                REFUND_TO,
+                Account {
+                    lamports: REFUND_TO_STARTING_LAMPORTS,
+                    ..Default::default()
+                },
+            ),
+            keyed_account_for_system_program(),
+        ],
+    )
+}
+
+/// Returns CUs consumed
+fn assert_rent_exempt_realloc(
+    acc: Pubkey,
+    original_len: usize,
+    new_len: usize,
+    payer: Pubkey,
+    payer_owner: Pubkey,
+) -> u64 {
+    let (ix, accounts) = setup(acc, original_len, new_len, payer, payer_owner);
+
+    let InstructionResult {
+        compute_units_consumed,
+        raw_result,
+        resulting_accounts,
+        ..
+    } = SVM.with(|svm| {
+        svm.process_and_validate_instruction(&ix, &accounts, &[Check::all_rent_exempt()])
+    });
+
+    raw_result.unwrap();
+
+    let rent = Rent::default();
+    let acc = &resulting_accounts[ACC_IDX].1;
+    let payer = &resulting_accounts[PAYER_IDX].1;
+    let refund_to = &resulting_accounts[REFUND_TO_IDX].1;
+
+    assert_eq!(acc.data.len(), new_len);
+    // exactly rent-exempt, no more no less
+    assert_eq!(acc.lamports, rent.minimum_balance(new_len));
+    if new_len < original_len {
+        // shrink only ever refunds to refund_to
+        assert_eq!(payer.lamports, PAYER_STARTING_LAMPORTS);
+    } else {
+        // grow only ever takes from payer
+        assert_eq!(refund_to.lamports, REFUND_TO_STARTING_LAMPORTS);
+    }
+    // lamports are conserved between acc, payer and refund_to
+    assert_eq!(
+        acc.lamports + payer.lamports + refund_to.lamports,
+        rent.minimum_balance(original_len) + PAYER_STARTING_LAMPORTS + REFUND_TO_STARTING_LAMPORTS
+    );
+
+    compute_units_consumed
+}
+
+const ACC: Pubkey = solana_pubkey::pubkey!("CkebHSWNvZ5w9Q3GTivrEomZZmwWFNqPpzVA9NFZxpg8");
+const PAYER: Pubkey = solana_pubkey::pubkey!("7A87rRA9qxBzRaJr7a8dHcmsPW3QfbnH63SjFzZSoz4Q");
+
+#[test]
+fn grow_payer_system_owned_cpi() {
+    let cus = assert_rent_exempt_realloc(ACC, 69, 420, PAYER, solana_system_program::id());
+    expect![""].assert_eq(&cus.to_string());
+}
+
+#[test]
+fn grow_payer_prog_owned_direct() {
+    let cus = assert_rent_exempt_realloc(ACC, 69, 420, PAYER, PROG_ID);
+    expect![""].assert_eq(&cus.to_string());
+}
+
+#[test]
+fn shrink_refunds_refund_to() {
+    let cus = assert_rent_exempt_realloc(ACC, 420, 69, PAYER, solana_system_program::id());
+    expect![""].assert_eq(&cus.to_string());
+}
+
+const PK_EXCL: [[u8; 32]; 3] = [[0; 32], PROG_ID.to_bytes(), REFUND_TO.to_bytes()];
+
+proptest! {
+    #[test]
+    fn realloc_stays_exactly_rent_exempt(
+        [acc, payer] in two_different_pubkeys(),
+        original_len in 0usize..=1024 * 10,
+        delta in -(MAX_PERMITTED_DATA_INCREASE as isize)..=MAX_PERMITTED_DATA_INCREASE as isize,
+        is_payer_prog_owned: bool,
+    ) {
+        silence_mollusk_prog_logs();
+
+        for pk in [acc, payer] {
+            if PK_EXCL.contains(&pk) {
+                return Ok(());
+            }
+        }
+        let new_len = original_len.saturating_add_signed(delta);
+
+        let [acc, payer] = [acc, payer].map(Pubkey::new_from_array);
+        let payer_owner = if is_payer_prog_owned {
+            PROG_ID
+        } else {
+            solana_system_program::id()
+        };
+
+        assert_rent_exempt_realloc(acc, original_len, new_len, payer, payer_owner);
+    }
+}
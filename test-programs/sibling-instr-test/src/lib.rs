@@ -0,0 +1,118 @@
+//! This program accepts the following accounts:
+//!
+//! 1. this program
+//! 2. system program
+//! 3. from, signer
+//! 4. to
+//!
+//! and the following input data:
+//!
+//! - u8: [`TRANSFER_THEN_CHECK`]
+//! - u64: lamports
+//!
+//! It transfers `lamports` from `from` to `to` via system program CPI,
+//! then CPIs itself with [`CHECK`], which is at the same stack height as the transfer.
+//!
+//! With [`CHECK`], it verifies that the most recently processed sibling instruction
+//! is the transfer, with the expected program ID, data and accounts,
+//! and that there are no other processed sibling instructions.
+
+#![allow(unexpected_cfgs)]
+
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::{BuiltInProgramError, ProgramError},
+    Cpi,
+};
+use jiminy_sibling_instr::{stack_height, SiblingInstr, TRANSACTION_LEVEL_STACK_HEIGHT};
+use jiminy_system_prog_interface::{transfer, TransferIxData, TRANSFER_IX_DATA_LEN};
+
+pub const MAX_ACCS: usize = 4;
+pub const MAX_CPI_ACCS: usize = 4;
+
+pub const TRANSFER_THEN_CHECK: u8 = 0;
+pub const CHECK: u8 = 1;
+
+jiminy_entrypoint::entrypoint!(process_ix, MAX_ACCS);
+
+fn process_ix(
+    abr: &mut Abr,
+    accounts: &[AccountHandle<'_>],
+    data: &[u8],
+    _prog_id: &[u8; 32],
+) -> Result<(), ProgramError> {
+    let Some((tag, lamports)) = data.split_first().and_then(|(tag, rem)| {
+        rem.first_chunk()
+            .map(|lamports| (*tag, u64::from_le_bytes(*lamports)))
+    }) else {
+        return Err(ProgramError::from_builtin(
+            BuiltInProgramError::InvalidInstructionData,
+        ));
+    };
+    match tag {
+        TRANSFER_THEN_CHECK => transfer_then_check(abr, accounts, lamports),
+        CHECK => check(abr, accounts, lamports),
+        _ => Err(ProgramError::from_builtin(
+            BuiltInProgramError::InvalidInstructionData,
+        )),
+    }
+}
+
+fn transfer_then_check(
+    abr: &mut Abr,
+    accounts: &[AccountHandle<'_>],
+    lamports: u64,
+) -> Result<(), ProgramError> {
+    let Some([this_prog, sys_prog, from, to]) = accounts.first_chunk().copied() else {
+        return Err(ProgramError::from_builtin(
+            BuiltInProgramError::NotEnoughAccountKeys,
+        ));
+    };
+    let mut cpi = Cpi::<MAX_CPI_ACCS>::new();
+
+    transfer(&mut cpi, abr, sys_prog, from, to, lamports, &[])?;
+
+    let mut check_data = [0u8; 9];
+    check_data[0] = CHECK;
+    check_data[1..].copy_from_slice(&lamports.to_le_bytes());
+    cpi.invoke_fwd_handle(abr, this_prog, &check_data, [from, to])
+}
+
+fn check(abr: &mut Abr, accounts: &[AccountHandle<'_>], lamports: u64) -> Result<(), ProgramError> {
+    let Some([from, to]) = accounts.first_chunk().copied() else {
+        return Err(ProgramError::from_builtin(
+            BuiltInProgramError::NotEnoughAccountKeys,
+        ));
+    };
+    if stack_height() != TRANSACTION_LEVEL_STACK_HEIGHT + 1 {
+        return Err(ProgramError::custom(1));
+    }
+
+    let Ok(Some(sibling)) = SiblingInstr::<TRANSFER_IX_DATA_LEN, 2>::get(0) else {
+        return Err(ProgramError::custom(2));
+    };
+    if *sibling.program_id() != jiminy_system_prog_interface::ID {
+        return Err(ProgramError::custom(3));
+    }
+    if sibling.data() != TransferIxData::new(lamports).as_buf() {
+        return Err(ProgramError::custom(4));
+    }
+    let [sib_from, sib_to] = sibling.accounts() else {
+        return Err(ProgramError::custom(5));
+    };
+    if sib_from.key() != abr.get(from).key()
+        || !sib_from.is_signer()
+        || !sib_from.is_writable()
+        || sib_to.key() != abr.get(to).key()
+        || sib_to.is_signer()
+        || !sib_to.is_writable()
+    {
+        return Err(ProgramError::custom(6));
+    }
+
+    // the outer instruction is at a lower stack height, so isn't a sibling
+    if !matches!(SiblingInstr::<0, 0>::get(1), Ok(None)) {
+        return Err(ProgramError::custom(7));
+    }
+    Ok(())
+}
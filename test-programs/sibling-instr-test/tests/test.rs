@@ -0,0 +1,100 @@
+#![cfg(feature = "test-sbf")]
+
+use jiminy_test_utils::{bench_binsize, expect_test::expect, silence_mollusk_prog_logs};
+use mollusk_svm::{
+    program::{create_program_account_loader_v3, keyed_account_for_system_program},
+    result::InstructionResult,
+    Mollusk,
+};
+use proptest::prelude::*;
+use sibling_instr_test::TRANSFER_THEN_CHECK;
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+const PROG_NAME: &str = "sibling_instr_test";
+const PROG_ID: Pubkey = solana_pubkey::pubkey!("5ZnnfzwW7Hc8QQDqYoTfkXXcA4kPTzA4CYsv9uBcwcyC");
+
+const FROM: Pubkey = solana_pubkey::pubkey!("FpaavSQvEQhPDoQoLUHhmBsKZsG2WJQXj7FBCSPE1TZ1");
+const TO: Pubkey = solana_pubkey::pubkey!("9diwgHx6xrDjrvXUVx8B4drJMzv9ddh9fBSx59EWjFPU");
+
+thread_local! {
+    static SVM: Mollusk = Mollusk::new(&PROG_ID, PROG_NAME);
+}
+
+#[test]
+fn binsize_bench() {
+    bench_binsize(PROG_NAME, expect![""]);
+}
+
+/// Returns CUs consumed
+fn assert_transfer_is_sibling(lamports: u64) -> u64 {
+    let mut data = vec![TRANSFER_THEN_CHECK];
+    data.extend(lamports.to_le_bytes());
+
+    let ix = Instruction::new_with_bytes(
+        PROG_ID,
+        &data,
+        vec![
+            AccountMeta {
+                pubkey: PROG_ID,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: solana_system_program::id(),
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: FROM,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: TO,
+                is_signer: false,
+                is_writable: true,
+            },
+        ],
+    );
+    let accounts = [
+        (PROG_ID, create_program_account_loader_v3(&PROG_ID)),
+        keyed_account_for_system_program(),
+        (
+            FROM,
+            Account {
+                lamports,
+                ..Default::default()
+            },
+        ),
+        (TO, Account::default()),
+    ];
+
+    let InstructionResult {
+        compute_units_consumed,
+        raw_result,
+        resulting_accounts,
+        ..
+    } = SVM.with(|svm| svm.process_instruction(&ix, &accounts));
+
+    raw_result.unwrap();
+    assert_eq!(resulting_accounts[2].1.lamports, 0);
+    assert_eq!(resulting_accounts[3].1.lamports, lamports);
+
+    compute_units_consumed
+}
+
+#[test]
+fn transfer_is_sibling_basic_cus() {
+    let cus = assert_transfer_is_sibling(1_000_000_000);
+    expect![""].assert_eq(&cus.to_string());
+}
+
+proptest! {
+    #[test]
+    fn transfer_is_sibling(lamports: u64) {
+        silence_mollusk_prog_logs();
+        assert_transfer_is_sibling(lamports);
+    }
+}
@@ -3,7 +3,9 @@
 
 use std::{fs::File, os::unix::fs::MetadataExt, path::PathBuf};
 
+use base64::{engine::general_purpose::STANDARD, Engine};
 use expect_test::Expect;
+use jiminy_log::{Event, EVENT_DISCRIMINATOR_LEN};
 use proptest::{
     prelude::{Just, Strategy},
     strategy::Union,
@@ -60,3 +62,49 @@ pub fn bench_binsize(prog_name: &str, expect: Expect) {
     .size();
     expect.assert_eq(&size.to_string());
 }
+
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Returns the data of every `Program data: ` line in `logs`,
+/// e.g. from mollusk's `LogCollector`.
+///
+/// Each line's space-separated base64 fields are decoded and concatenated.
+pub fn program_data<S: AsRef<str>>(logs: &[S]) -> Vec<Vec<u8>> {
+    logs.iter()
+        .filter_map(|l| l.as_ref().strip_prefix(PROGRAM_DATA_PREFIX))
+        .map(|fields| {
+            fields
+                .split(' ')
+                .flat_map(|f| STANDARD.decode(f).unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the data of every `Program data: ` line in `logs` that is
+/// a single base64 field, which is what Anchor's `EventParser` decodes events from,
+/// e.g. events logged with `jiminy_log::emit_anchor`.
+pub fn anchor_event_data<S: AsRef<str>>(logs: &[S]) -> Vec<Vec<u8>> {
+    logs.iter()
+        .filter_map(|l| l.as_ref().strip_prefix(PROGRAM_DATA_PREFIX))
+        .filter_map(|field| STANDARD.decode(field).ok())
+        .collect()
+}
+
+/// Decodes every `E` emitted with `jiminy_log::emit` in `logs`, in order.
+///
+/// `decode` is called with the event body of each `Program data: ` line
+/// that starts with `E::DISCRIMINATOR`.
+pub fn decode_events<E: Event, S: AsRef<str>>(
+    logs: &[S],
+    decode: impl FnMut(&[u8]) -> E,
+) -> Vec<E> {
+    program_data(logs)
+        .iter()
+        .filter_map(|d| {
+            d.split_first_chunk::<EVENT_DISCRIMINATOR_LEN>()
+                .and_then(|(discm, body)| (*discm == E::DISCRIMINATOR).then_some(body))
+        })
+        .map(decode)
+        .collect()
+}